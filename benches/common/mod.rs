@@ -0,0 +1,34 @@
+// # Bench inputs
+//
+// The benches draw their keys and edit positions from the same generator, so that two runs (or two
+// branches) time the same work. Not every bench uses every function.
+#![allow(dead_code)]
+
+// Xorshift64. The seed must not be 0.
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "xorshift is stuck at 0");
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // In `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    // `len` lowercase ASCII letters.
+    pub fn word(&mut self, len: usize) -> String {
+        (0..len)
+            .map(|_| (b'a' + self.below(26) as u8) as char)
+            .collect()
+    }
+}
@@ -0,0 +1,58 @@
+// # HashDoS
+//
+// The colliding keys of `collections::hashdos` against a map using FNV-1a, and against the same map
+// using SipHash-1-3 (std's `RandomState`, and this crate's). Random keys of the same shape show what each
+// hasher does with a fair input:
+//
+//   cargo bench --bench hashdos
+//
+// With FNV and colliding keys, the time per insert grows with the number of keys (the total grows as its
+// square) and lookups go through half of the table on average. Everywhere else, the time per insert stays flat
+// and almost every lookup ends in its first group.
+
+use std::collections::hash_map::RandomState;
+
+use rust_trainning::collections::hash::{FnvBuildHasher, SipBuildHasher};
+use rust_trainning::collections::hashdos::{attack, fnv_colliding_keys, random_keys, Attack};
+
+const SIZES_LOG2: &[u32] = &[10, 12, 14];
+
+fn attacks(keys: &[String]) -> [Attack; 3] {
+    [
+        attack("RandomState", keys, RandomState::new()),
+        attack("SipHash-1-3", keys, SipBuildHasher::new()),
+        attack("FNV-1a", keys, FnvBuildHasher::default()),
+    ]
+}
+
+fn main() {
+    let mut summary = Vec::new();
+    for &log2 in SIZES_LOG2 {
+        for (input, keys) in [
+            ("colliding", fnv_colliding_keys(log2)),
+            ("random", random_keys(log2, 0x9E37_79B9_7F4A_7C15)),
+        ] {
+            println!("# {} {} keys\n", 1 << log2, input);
+            for attack in attacks(&keys) {
+                println!("{}", attack);
+                summary.push((input, attack));
+            }
+        }
+    }
+
+    println!("ns per insert\n");
+    println!(
+        "{:>10}  {:>10}  {:>12}  {:>12}  {:>12}",
+        "keys", "", "RandomState", "SipHash-1-3", "FNV-1a"
+    );
+    for row in summary.chunks(3) {
+        println!(
+            "{:>10}  {:>10}  {:>12.1}  {:>12.1}  {:>12.1}",
+            row[0].1.keys,
+            row[0].0,
+            row[0].1.ns_per_insert(),
+            row[1].1.ns_per_insert(),
+            row[2].1.ns_per_insert()
+        );
+    }
+}
@@ -0,0 +1,85 @@
+// # Hashers
+//
+// The hashers of `collections::hash`, and std's `RandomState`, on three kinds of keys from the hashmap
+// lesson: small integers, long strings and `Viking`s. For each, the time to hash one key alone, and the
+// time of an insert followed by a get in a std `HashMap` using that hasher:
+//
+//   cargo bench --bench hashers
+//
+// Expect Fx to win on integers, FNV to fall behind on long strings (one multiplication per byte), and the
+// two SipHash-1-3 (std's and this crate's) to sit in the middle everywhere.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::hash::{BuildHasher, Hash};
+use std::hint::black_box;
+use std::time::Instant;
+
+use rust_trainning::collections::hash::{FnvBuildHasher, FxBuildHasher, SipBuildHasher};
+
+mod common;
+
+use common::Random;
+
+const KEYS: usize = 100_000;
+
+// A copy of the `Viking` of `topics::hashmap`, which the lessons keep to themselves, plus `Clone` for the
+// map's keys. Same fields in the same order, so it hashes the same.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Viking {
+    name: String,
+    country: String,
+}
+
+// Nanoseconds per key: hashing alone, then insert + get in a `HashMap`.
+fn bench<K: Hash + Eq + Clone, S: BuildHasher + Clone>(keys: &[K], build: S) -> [f64; 2] {
+    let per_key = |start: Instant| start.elapsed().as_nanos() as f64 / keys.len() as f64;
+
+    let start = Instant::now();
+    for key in keys {
+        black_box(build.hash_one(key));
+    }
+    let hash = per_key(start);
+
+    let mut map = HashMap::with_capacity_and_hasher(keys.len(), build);
+    let start = Instant::now();
+    for (i, key) in keys.iter().enumerate() {
+        map.insert(key.clone(), i);
+    }
+    for key in keys {
+        black_box(map.get(key));
+    }
+    [hash, per_key(start)]
+}
+
+fn table<K: Hash + Eq + Clone>(title: &str, keys: &[K]) {
+    println!("{}\n", title);
+    println!("{:>10}  {:>10}  {:>14}", "", "hash", "insert + get");
+    let rows = [
+        ("std", bench(keys, RandomState::new())),
+        ("sip13", bench(keys, SipBuildHasher::new())),
+        ("fnv", bench(keys, FnvBuildHasher::default())),
+        ("fx", bench(keys, FxBuildHasher::default())),
+    ];
+    for (name, [hash, map]) in rows {
+        println!("{:>10}  {:>10.1}  {:>14.1}", name, hash, map);
+    }
+    println!();
+}
+
+fn main() {
+    let mut random = Random::new(0x9E37_79B9_7F4A_7C15);
+    let integers: Vec<u64> = (0..KEYS).map(|_| random.below(1 << 20) as u64).collect();
+    let strings: Vec<String> = (0..KEYS / 10).map(|_| random.word(1024)).collect();
+    let vikings: Vec<Viking> = (0..KEYS)
+        .map(|_| Viking {
+            name: random.word(6),
+            country: random.word(8),
+        })
+        .collect();
+
+    println!("ns per key\n");
+    table("u64 below 2^20", &integers);
+    table("1 KiB strings", &strings);
+    table("Viking { name, country }", &vikings);
+}
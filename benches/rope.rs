@@ -0,0 +1,105 @@
+// # Rope vs String
+//
+// Why an editor doesn't keep its buffer in a `String`: inserting a word or removing a char moves every
+// byte after the edit, so the cost of an edit grows with the text even when its byte offset is known
+// ("String, bytes"). An editor knows chars, not bytes, and finding the byte of a char walks the string
+// from its start as well ("String, chars"). A `Rope` only touches one chunk and the path of the tree down
+// to it, and its time barely moves: from about a megabyte on, the String loses even with byte offsets.
+//
+// Each row is 1000 such edits at random places, on texts from 10 kB to 10 MB (`cargo bench --bench rope`).
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rust_trainning::text::{self, Rope};
+
+mod common;
+
+use common::Random;
+
+const EDITS: usize = 1000;
+const SIZES: &[usize] = &[10_000, 100_000, 1_000_000, 10_000_000];
+
+// A text of about `size` bytes, with a line every 60 chars and some chars that aren't ASCII.
+fn sample(size: usize) -> String {
+    let line = "The 🚀 goes to the 🌘, and ぁ is three bytes long in UTF-8.\n";
+    line.repeat(size / line.len() + 1)
+}
+
+fn time(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+fn bench_string_bytes(text: &str) -> Duration {
+    let mut s = text.to_string();
+    let mut random = Random::new(42);
+    time(|| {
+        for i in 0..EDITS {
+            let mut byte = random.below(s.len());
+            while !s.is_char_boundary(byte) {
+                byte -= 1;
+            }
+            if i % 2 == 0 {
+                s.insert_str(byte, "edit");
+            } else {
+                s.remove(byte);
+            }
+        }
+        black_box(&s);
+    })
+}
+
+fn bench_string_chars(text: &str) -> Duration {
+    let mut s = text.to_string();
+    let mut random = Random::new(42);
+    time(|| {
+        for i in 0..EDITS {
+            let at = random.below(s.chars().count());
+            let byte = text::char_to_byte(&s, at).unwrap();
+            if i % 2 == 0 {
+                s.insert_str(byte, "edit");
+            } else {
+                let end = text::char_to_byte(&s[byte..], 1).unwrap_or(0);
+                s.replace_range(byte..byte + end, "");
+            }
+        }
+        black_box(&s);
+    })
+}
+
+fn bench_rope(text: &str) -> Duration {
+    let mut rope = Rope::from(text);
+    let mut random = Random::new(42);
+    time(|| {
+        for i in 0..EDITS {
+            let at = random.below(rope.len_chars());
+            if i % 2 == 0 {
+                rope.insert(at, "edit");
+            } else {
+                rope.remove(at..(at + 1).min(rope.len_chars()));
+            }
+        }
+        black_box(&rope);
+    })
+}
+
+fn main() {
+    println!("{} edits at random positions\n", EDITS);
+    println!(
+        "{:>12}  {:>14}  {:>14}  {:>10}",
+        "text size", "String, bytes", "String, chars", "Rope"
+    );
+    let ms = |duration: Duration| duration.as_secs_f64() * 1000.0;
+    for &size in SIZES {
+        let text = sample(size);
+        println!(
+            "{:>10} B  {:>11.2} ms  {:>11.2} ms  {:>7.2} ms",
+            text.len(),
+            ms(bench_string_bytes(&text)),
+            ms(bench_string_chars(&text)),
+            ms(bench_rope(&text))
+        );
+    }
+}
@@ -0,0 +1,122 @@
+// # SwissTable vs std
+//
+// How far the teaching table is from the real one. std reads 16 control bytes with one SSE2 instruction
+// and never checks a slot for `None`; `collections::swiss` reads 8 with integer arithmetic and keeps its
+// slots in `Option`s, so it should be somewhat slower, and both should slow down alike as the table
+// outgrows the CPU caches.
+//
+// Both maps hash with `RandomState` (SipHash-1-3), so that the difference is the table. Run it with
+// `cargo bench --bench swiss`.
+
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap as StdMap;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use rust_trainning::collections::swiss::HashMap as SwissMap;
+
+mod common;
+
+use common::Random;
+
+const SIZES: &[usize] = &[1_000, 100_000, 1_000_000];
+
+// The operations the bench needs, on both maps.
+trait Map: Default {
+    fn insert(&mut self, key: u64, value: u64);
+    fn get(&self, key: u64) -> Option<&u64>;
+    fn remove(&mut self, key: u64) -> Option<u64>;
+}
+
+impl Map for StdMap<u64, u64, RandomState> {
+    fn insert(&mut self, key: u64, value: u64) {
+        StdMap::insert(self, key, value);
+    }
+
+    fn get(&self, key: u64) -> Option<&u64> {
+        StdMap::get(self, &key)
+    }
+
+    fn remove(&mut self, key: u64) -> Option<u64> {
+        StdMap::remove(self, &key)
+    }
+}
+
+impl Map for SwissMap<u64, u64, RandomState> {
+    fn insert(&mut self, key: u64, value: u64) {
+        SwissMap::insert(self, key, value);
+    }
+
+    fn get(&self, key: u64) -> Option<&u64> {
+        SwissMap::get(self, &key)
+    }
+
+    fn remove(&mut self, key: u64) -> Option<u64> {
+        SwissMap::remove(self, &key)
+    }
+}
+
+// Keys spread over the whole `u64` range, in a fixed order.
+fn keys(count: usize) -> Vec<u64> {
+    let mut random = Random::new(0x9E37_79B9_7F4A_7C15);
+    (0..count).map(|_| random.next_u64()).collect()
+}
+
+fn time(f: impl FnOnce()) -> Duration {
+    let start = Instant::now();
+    f();
+    start.elapsed()
+}
+
+// Nanoseconds per operation: insert, get (hits), get (misses), remove.
+fn bench<M: Map>(keys: &[u64], missing: &[u64]) -> [f64; 4] {
+    let mut map = M::default();
+    let per_op = |duration: Duration| duration.as_nanos() as f64 / keys.len() as f64;
+
+    let insert = time(|| {
+        for &key in keys {
+            map.insert(key, key);
+        }
+    });
+    let hits = time(|| {
+        for &key in keys {
+            black_box(map.get(key));
+        }
+    });
+    let misses = time(|| {
+        for &key in missing {
+            black_box(map.get(key));
+        }
+    });
+    let remove = time(|| {
+        for &key in keys {
+            black_box(map.remove(key));
+        }
+    });
+    [insert, hits, misses, remove].map(per_op)
+}
+
+fn main() {
+    println!("ns per operation, u64 keys\n");
+    println!(
+        "{:>10}  {:>6}  {:>14}  {:>14}  {:>14}  {:>14}",
+        "entries", "", "insert", "get (hit)", "get (miss)", "remove"
+    );
+    for &size in SIZES {
+        let all = keys(size * 2);
+        let (keys, missing) = all.split_at(size);
+        let rows = [
+            ("std", bench::<StdMap<u64, u64, RandomState>>(keys, missing)),
+            (
+                "swiss",
+                bench::<SwissMap<u64, u64, RandomState>>(keys, missing),
+            ),
+        ];
+        for (name, [insert, hits, misses, remove]) in rows {
+            println!(
+                "{:>10}  {:>6}  {:>14.1}  {:>14.1}  {:>14.1}  {:>14.1}",
+                size, name, insert, hits, misses, remove
+            );
+        }
+    }
+}
@@ -0,0 +1,178 @@
+// # Unicode Tables
+//
+// The grapheme segmenter (`src/text/grapheme.rs`) looks up three properties of every char, read here from
+// the Unicode data files vendored in `data/ucd/` and written as sorted range tables to
+// `$OUT_DIR/grapheme_tables.rs`:
+//
+// - `GraphemeBreakProperty.txt` -> `GRAPHEME_BREAK`, the Grapheme_Cluster_Break of a char
+// - `emoji-data.txt`            -> `EXTENDED_PICTOGRAPHIC`
+// - `DerivedCoreProperties.txt` -> `INDIC_CONJUNCT_BREAK`, the InCB of a char
+//
+// The display width (`src/text/width.rs`) looks up two more, in `$OUT_DIR/width_tables.rs`:
+//
+// - `DerivedGeneralCategory.txt` -> `ZERO`, the chars of categories Mn, Me and Cf
+// - `EastAsianWidth.txt`         -> `WIDE`, the chars of East_Asian_Width W and F
+//
+// A line of these files is `0600..0605 ; Prepend # comment` (or `094D ; InCB; Linker`). Upgrading
+// Unicode is replacing the files: the tables follow.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+const UCD: &str = "data/ucd";
+
+// The names of the values in the files and of the enum variants that stand for them.
+const GRAPHEME_BREAK: &[(&str, &str)] = &[
+    ("CR", "Cr"),
+    ("LF", "Lf"),
+    ("Control", "Control"),
+    ("Extend", "Extend"),
+    ("ZWJ", "Zwj"),
+    ("Regional_Indicator", "RegionalIndicator"),
+    ("Prepend", "Prepend"),
+    ("SpacingMark", "SpacingMark"),
+    ("L", "L"),
+    ("V", "V"),
+    ("T", "T"),
+    ("LV", "Lv"),
+    ("LVT", "Lvt"),
+];
+
+const INDIC_CONJUNCT_BREAK: &[(&str, &str)] = &[
+    ("Linker", "Linker"),
+    ("Consonant", "Consonant"),
+    ("Extend", "Extend"),
+];
+
+struct Range {
+    start: u32,
+    end: u32,
+    value: &'static str,
+}
+
+fn main() {
+    let mut out = String::from("// Generated by build.rs from data/ucd/, do not edit.\n\n");
+
+    let grapheme_break = ranges("GraphemeBreakProperty.txt", |fields| {
+        variant(GRAPHEME_BREAK, fields.first()?)
+    });
+    out.push_str(&table(
+        "GRAPHEME_BREAK",
+        "(char, char, Property)",
+        &grapheme_break,
+        "Property::",
+    ));
+
+    let pictographic = ranges("emoji-data.txt", |fields| {
+        (fields.first() == Some(&"Extended_Pictographic")).then_some("")
+    });
+    out.push_str(&table(
+        "EXTENDED_PICTOGRAPHIC",
+        "(char, char)",
+        &pictographic,
+        "",
+    ));
+
+    let conjunct = ranges("DerivedCoreProperties.txt", |fields| match fields {
+        ["InCB", value] => variant(INDIC_CONJUNCT_BREAK, value),
+        _ => None,
+    });
+    out.push_str(&table(
+        "INDIC_CONJUNCT_BREAK",
+        "(char, char, Conjunct)",
+        &conjunct,
+        "Conjunct::",
+    ));
+
+    write("grapheme_tables.rs", out);
+
+    let mut out = String::from("// Generated by build.rs from data/ucd/, do not edit.\n\n");
+
+    let zero = ranges("DerivedGeneralCategory.txt", |fields| {
+        matches!(fields.first(), Some(&("Mn" | "Me" | "Cf"))).then_some("")
+    });
+    out.push_str(&table("ZERO", "(char, char)", &zero, ""));
+
+    let wide = ranges("EastAsianWidth.txt", |fields| {
+        matches!(fields.first(), Some(&("W" | "F"))).then_some("")
+    });
+    out.push_str(&table("WIDE", "(char, char)", &wide, ""));
+
+    write("width_tables.rs", out);
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", UCD);
+}
+
+fn write(file: &str, tables: String) {
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join(file);
+    fs::write(path, tables).unwrap();
+}
+
+fn variant(names: &[(&str, &'static str)], name: &str) -> Option<&'static str> {
+    names
+        .iter()
+        .find(|(file, _)| *file == name)
+        .map(|(_, variant)| *variant)
+}
+
+// The ranges of the lines that `value` keeps, given the fields after the code points; sorted and merged
+// with their neighbours of the same value.
+fn ranges(file: &str, value: impl Fn(&[&str]) -> Option<&'static str>) -> Vec<Range> {
+    let path = Path::new(UCD).join(file);
+    let text = fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {}", path.display(), e));
+
+    let mut ranges = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        let Some(value) = value(&fields[1..]) else {
+            continue;
+        };
+        let (start, end) = fields[0].split_once("..").unwrap_or((fields[0], fields[0]));
+        let code_point = |hex: &str| {
+            u32::from_str_radix(hex, 16)
+                .unwrap_or_else(|e| panic!("{}:{}: {}: {}", file, number + 1, hex, e))
+        };
+        ranges.push(Range {
+            start: code_point(start),
+            end: code_point(end),
+            value,
+        });
+    }
+
+    ranges.sort_by_key(|range| range.start);
+    let mut merged: Vec<Range> = Vec::new();
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if last.end + 1 == range.start && last.value == range.value => {
+                last.end = range.end
+            }
+            Some(last) if last.end >= range.start => {
+                panic!("{}: {:04X} is listed twice", file, range.start)
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+fn table(name: &str, kind: &str, ranges: &[Range], prefix: &str) -> String {
+    let mut out = format!("const {}: &[{}] = &[\n", name, kind);
+    for range in ranges {
+        out.push_str(&format!(
+            "    ('\\u{{{:X}}}', '\\u{{{:X}}}'",
+            range.start, range.end
+        ));
+        if !range.value.is_empty() {
+            out.push_str(&format!(", {}{}", prefix, range.value));
+        }
+        out.push_str("),\n");
+    }
+    out.push_str("];\n\n");
+    out
+}
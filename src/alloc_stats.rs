@@ -0,0 +1,126 @@
+// # Allocation Statistics
+//
+// Some lessons make claims about the cost of the heap: `Box::new` allocates, a `String` or a `Vec` that
+// outgrows its capacity reallocates, `with_capacity` avoids it. `run --stats` shows it, with the wall time
+// and the allocator calls of every example:
+//
+//   cargo run --features alloc-stats -- run --stats string
+//
+// With the `alloc-stats` feature, the whole program runs on `Counting`: a thin wrapper around the system
+// allocator that counts every call. Without it the counters stay at zero and only the time is reported.
+//
+// The counters are global. Examples run one at a time on the main thread, so what happens between two
+// snapshots is what the example did.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+static ALLOCS: AtomicUsize = AtomicUsize::new(0);
+static DEALLOCS: AtomicUsize = AtomicUsize::new(0);
+static REALLOCS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Counting;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        ALLOCS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        DEALLOCS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    // A reallocation may move the data to a new block, its bytes count is what it adds.
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        REALLOCS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size.saturating_sub(layout.size()), Ordering::Relaxed);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub allocs: usize,
+    pub deallocs: usize,
+    pub reallocs: usize,
+    // Bytes asked for by allocations, plus what reallocations added.
+    pub bytes: usize,
+}
+
+impl Stats {
+    pub fn now() -> Self {
+        Self {
+            allocs: ALLOCS.load(Ordering::Relaxed),
+            deallocs: DEALLOCS.load(Ordering::Relaxed),
+            reallocs: REALLOCS.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+        }
+    }
+
+    pub fn since(&self, earlier: &Stats) -> Stats {
+        Stats {
+            allocs: self.allocs - earlier.allocs,
+            deallocs: self.deallocs - earlier.deallocs,
+            reallocs: self.reallocs - earlier.reallocs,
+            bytes: self.bytes - earlier.bytes,
+        }
+    }
+}
+
+pub struct Measure {
+    pub duration: Duration,
+    pub stats: Stats,
+}
+
+pub fn measure(f: impl FnOnce()) -> Measure {
+    let before = Stats::now();
+    let start = Instant::now();
+    f();
+    let duration = start.elapsed();
+
+    Measure {
+        duration,
+        stats: Stats::now().since(&before),
+    }
+}
+
+// `0.042 ms, 1 allocation, 5 reallocations, 0 deallocations, 256 bytes`
+pub fn summary(measure: &Measure) -> String {
+    let time = format!("{:.3} ms", measure.duration.as_secs_f64() * 1000.0);
+    if !ENABLED {
+        return format!(
+            "{} (build with `--features alloc-stats` to count allocations)",
+            time
+        );
+    }
+
+    let plural = |count: usize, what: &str| {
+        format!("{} {}{}", count, what, if count == 1 { "" } else { "s" })
+    };
+    let stats = &measure.stats;
+    format!(
+        "{}, {}, {}, {}, {}",
+        time,
+        plural(stats.allocs, "allocation"),
+        plural(stats.reallocs, "reallocation"),
+        plural(stats.deallocs, "deallocation"),
+        plural(stats.bytes, "byte")
+    )
+}
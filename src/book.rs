@@ -0,0 +1,173 @@
+// # Lesson Book
+//
+// Turns the lessons into a Markdown book that can be read without an editor, or served with `mdbook`:
+//
+// <out>/README.md        -> introduction and table of contents
+// <out>/SUMMARY.md       -> mdBook's table of contents
+// <out>/<topic>.md       -> one chapter per module, in curriculum order (`curriculum::curriculum`)
+//
+// A chapter is the module read from top to bottom: its `//` comment blocks become prose and the code
+// between them is copied as is from the real source, so the book can't drift away from the lessons.
+//
+// Comments are written for a terminal, not for Markdown, so a few things are adapted:
+// - The module's `# Heading`s become `##`, as the chapter title is the topic title.
+// - Tables, diagrams (`|` columns, `=====` / `-----` rules, indented lines) and commented-out code are
+//   kept in `text` blocks so their alignment survives.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::curriculum;
+use crate::lesson::{self, Block};
+use crate::registry::Topic;
+
+pub const DEFAULT_DIR: &str = "book";
+
+// Topics in curriculum order: each chapter comes after the ones it builds on.
+pub fn chapters() -> Result<Vec<&'static Topic>, String> {
+    curriculum::curriculum()
+}
+
+// Writes the book into `dir` and returns the files it created.
+pub fn write(dir: &Path) -> io::Result<Vec<PathBuf>> {
+    let chapters = chapters().map_err(io::Error::other)?;
+    fs::create_dir_all(dir)?;
+
+    let mut files = Vec::new();
+    let mut save = |name: String, contents: String| -> io::Result<()> {
+        let path = dir.join(name);
+        fs::write(&path, contents)?;
+        files.push(path);
+        Ok(())
+    };
+
+    save("README.md".to_string(), introduction(&chapters))?;
+    save("SUMMARY.md".to_string(), summary(&chapters))?;
+    for topic in chapters {
+        save(format!("{}.md", topic.name), chapter(topic))?;
+    }
+
+    Ok(files)
+}
+
+fn contents(chapters: &[&Topic]) -> String {
+    chapters
+        .iter()
+        .map(|topic| format!("- [{}]({}.md)\n", topic.title, topic.name))
+        .collect()
+}
+
+pub fn introduction(chapters: &[&Topic]) -> String {
+    format!(
+        "# Rust Trainning\n\n\
+         One chapter per lesson of `src/topics`, generated from the source with `cargo run -- book`.\n\n\
+         {}",
+        contents(chapters)
+    )
+}
+
+pub fn summary(chapters: &[&Topic]) -> String {
+    format!(
+        "# Summary\n\n[Introduction](README.md)\n\n{}",
+        contents(chapters)
+    )
+}
+
+pub fn chapter(topic: &Topic) -> String {
+    let mut out = format!(
+        "# {}\n\n_Source: `src/topics/{}.rs`_\n\n",
+        topic.title, topic.name
+    );
+
+    for block in lesson::blocks(topic.source) {
+        match block {
+            Block::Prose(lines) => prose(&mut out, &lines),
+            Block::Code(lines) => fenced(&mut out, "rust", &lines),
+        }
+    }
+
+    if !topic.examples.is_empty() {
+        out.push_str("## Run the examples\n\n");
+        for example in topic.examples {
+            let kind = if example.exercise { " (exercise)" } else { "" };
+            let _ = writeln!(
+                out,
+                "- `cargo run -- run {}`: {}{}",
+                example.path(),
+                example.title,
+                kind
+            );
+        }
+        out.push('\n');
+    }
+
+    squeeze_blank_lines(&out)
+}
+
+fn squeeze_blank_lines(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blanks = 0;
+    for line in text.lines() {
+        blanks = if line.is_empty() { blanks + 1 } else { 0 };
+        if blanks <= 1 {
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+fn prose(out: &mut String, lines: &[&str]) {
+    let mut preformatted: Vec<&str> = Vec::new();
+
+    for &line in lines {
+        if is_preformatted(line) {
+            preformatted.push(line);
+            continue;
+        }
+        if !preformatted.is_empty() {
+            fenced(out, "text", &preformatted);
+            preformatted.clear();
+        }
+
+        if line.starts_with('#') {
+            // Headings need a blank line around them to be recognised.
+            let _ = writeln!(out, "\n#{}\n", line);
+        } else {
+            let _ = writeln!(out, "{}", line);
+        }
+    }
+
+    if !preformatted.is_empty() {
+        fenced(out, "text", &preformatted);
+    } else {
+        out.push('\n');
+    }
+}
+
+fn is_preformatted(line: &str) -> bool {
+    let trimmed = line.trim();
+    line.starts_with("    ")
+        || line.starts_with('\t')
+        || trimmed.starts_with('|')
+        || trimmed.contains(" | ")
+        || trimmed.starts_with("=====")
+        || trimmed.starts_with("-----")
+        || is_code(trimmed)
+}
+
+// Commented-out code, possibly with its own trailing comment.
+fn is_code(line: &str) -> bool {
+    let code = line.split(" //").next().unwrap_or(line).trim_end();
+    code.ends_with(';') || code.ends_with('{') || code.starts_with('}')
+}
+
+fn fenced(out: &mut String, language: &str, lines: &[&str]) {
+    let _ = writeln!(out, "```{}", language);
+    for line in lines {
+        let _ = writeln!(out, "{}", line);
+    }
+    out.push_str("```\n\n");
+}
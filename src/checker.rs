@@ -0,0 +1,401 @@
+// # Solution Checker
+//
+// `check <topic>::<exercise> <file>` compiles a learner's own solution with the local toolchain and runs
+// the exercise against it, on the learner's machine, without any service involved.
+//
+// The file is either:
+// - a whole lesson file, fixed by the learner (it still ends with the `use crate::registry::Example;` part);
+// - or only what the learner wrote: a fixed `fn exercise_1()`, or the `impl fmt::Display for Point` an
+//   exercise relies on... It is spliced into the lesson: the lesson's top-level items with the same name
+//   (and its `use` lines the submission repeats) are blanked out and the submission is included in their
+//   place, so the registered exercise runs against the learner's items, whichever of them it calls.
+//   The lesson keeps its line numbers, the submission is its own file.
+//
+// A small crate root is generated around the lesson, glob-importing the lesson crate so that `crate::...`
+// paths resolve, and compiled by `rustc` against it
+// (`--extern rust_trainning=target/debug/librust_trainning.rlib -L target/debug/deps`), then the exercise
+// function runs in a child process, in a sandbox:
+// - no network: the process gets its own user and network namespaces (`unshare -rn`), and the check
+//   refuses to run at all where they can't be created;
+// - a memory limit: `ulimit -v`, 512 MB by default;
+// - a timeout: 10 seconds by default, after which the process is killed.
+//
+// The sandbox is Unix only: elsewhere `check` refuses to run, the rest of the crate works the same.
+
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+#[cfg(unix)]
+use std::os::unix::process::ExitStatusExt;
+use std::path::{Path, PathBuf};
+use std::process::{self, Child, Command, ExitStatus, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::harness;
+use crate::lesson;
+use crate::registry::{self, Example};
+use crate::report;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Limits {
+    pub timeout: Duration,
+    pub memory_mb: u64,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(10),
+            memory_mb: 512,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Passed,
+    // One of the exercise's assertions failed (the panic happened on an `assert*!` line), with the
+    // panic message.
+    Failed(String),
+    // Anything else panicked.
+    Panicked(String),
+    DoesNotCompile(String),
+    TimedOut,
+    OutOfMemory,
+    // Killed by a signal that is not the timeout's.
+    Crashed(String),
+}
+
+pub struct Outcome {
+    pub verdict: Verdict,
+    pub stdout: String,
+    pub duration: Duration,
+}
+
+// What is needed to build against the lesson crate: the compiler and the crate as built by cargo.
+pub struct Toolchain {
+    rustc: PathBuf,
+    rlib: PathBuf,
+    deps: PathBuf,
+}
+
+impl Toolchain {
+    // `binary` is the running `rust-trainning`, the library is built next to it.
+    pub fn locate(binary: &Path) -> Result<Self, String> {
+        let dir = binary
+            .parent()
+            .ok_or_else(|| format!("no directory around {}", binary.display()))?;
+        let rlib = dir.join("librust_trainning.rlib");
+        if !rlib.is_file() {
+            return Err(format!(
+                "cannot find {}, build the lessons first (`cargo build`)",
+                rlib.display()
+            ));
+        }
+
+        Ok(Self {
+            rustc: env::var_os("RUSTC").map_or_else(|| PathBuf::from("rustc"), PathBuf::from),
+            rlib,
+            deps: dir.join("deps"),
+        })
+    }
+
+    // The lessons may use the crate's dependencies, they are passed along by name.
+    fn externs(&self) -> io::Result<Vec<String>> {
+        let mut externs = vec![format!("rust_trainning={}", self.rlib.display())];
+        let mut names = Vec::new();
+        for entry in fs::read_dir(&self.deps)? {
+            let path = entry?.path();
+            let file = path
+                .file_name()
+                .and_then(|f| f.to_str())
+                .unwrap_or_default();
+            let Some(name) = file
+                .strip_prefix("lib")
+                .and_then(|f| f.strip_suffix(".rlib"))
+                .and_then(|f| f.rsplit_once('-'))
+                .map(|(name, _hash)| name.to_string())
+            else {
+                continue;
+            };
+            if name != "rust_trainning" && !names.contains(&name) {
+                externs.push(format!("{}={}", name, path.display()));
+                names.push(name);
+            }
+        }
+        Ok(externs)
+    }
+}
+
+// A whole lesson file still has the part where it registers its examples.
+pub fn is_whole_lesson(source: &str) -> bool {
+    source.contains(lesson::REGISTRY_MARKER)
+}
+
+// The crate root compiled around the lesson, which is `include!`d from the same directory. Whatever the
+// lesson crate exports is in scope at the root, as it is in the crate itself.
+pub fn crate_root(exercise: &Example) -> String {
+    format!(
+        "// Generated by `rust-trainning check`.
+#![allow(dead_code, unused, nonstandard_style, mismatched_lifetime_syntaxes)]
+
+use rust_trainning::*;
+
+mod lesson {{
+    include!(\"lesson.rs\");
+
+    pub fn check() {{
+        {id}();
+    }}
+}}
+
+fn main() {{
+    lesson::check();
+}}
+",
+        id = exercise.id
+    )
+}
+
+// The lesson with the submission in it, `include!`d from `file`. The lesson items the submission
+// defines again, by kind and name (`fn exercise_1`, `impl fmt::Display for Point`...), and the `use` lines it
+// repeats are replaced by blank lines, so that errors in the lesson still point to the right line.
+pub fn splice(source: &str, submission: &str, file: &str) -> String {
+    let body = lesson::body(source);
+    let mut lines: Vec<&str> = body.lines().collect();
+
+    let submitted = lesson::items(submission);
+    let items = lesson::items(body);
+    for item in top_level(&items) {
+        if top_level(&submitted).any(|s| s.kind == item.kind && s.name == item.name) {
+            lines[item.doc_start..=item.end].fill("");
+        }
+    }
+
+    let uses: Vec<&str> = submission
+        .lines()
+        .filter(|line| line.starts_with("use "))
+        .collect();
+    for line in &mut lines {
+        if uses.contains(line) {
+            *line = "";
+        }
+    }
+
+    format!(
+        "{}\n\ninclude!({:?});\n\n{}",
+        lines.join("\n"),
+        file,
+        source[body.len()..].trim_start()
+    )
+}
+
+// The items that are not inside another one (methods are inside their impl).
+fn top_level<'i, 'a>(items: &'i [lesson::Item<'a>]) -> impl Iterator<Item = &'i lesson::Item<'a>> {
+    items.iter().filter(|item| {
+        !items
+            .iter()
+            .any(|outer| outer.start < item.start && item.end <= outer.end)
+    })
+}
+
+// `unshare -rn` needs user namespaces, which some kernels and containers disable.
+#[cfg(unix)]
+pub fn network_isolation() -> Result<(), String> {
+    let status = Command::new("unshare")
+        .args(["-rn", "true"])
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .map_err(|e| format!("cannot run `unshare`: {}", e))?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(
+            "`unshare -rn` is not permitted here, the solution can't be cut off from the network"
+                .to_string(),
+        )
+    }
+}
+
+#[cfg(not(unix))]
+pub fn network_isolation() -> Result<(), String> {
+    Err(
+        "the sandbox is not supported on this platform, it needs `unshare` and `ulimit`"
+            .to_string(),
+    )
+}
+
+pub fn check(
+    toolchain: &Toolchain,
+    exercise: &'static Example,
+    solution: &Path,
+    limits: Limits,
+) -> Result<Outcome, String> {
+    let topic = registry::topic(exercise.topic)
+        .ok_or_else(|| format!("unknown topic `{}`", exercise.topic))?;
+    let source = fs::read_to_string(solution)
+        .map_err(|e| format!("cannot read {}: {}", solution.display(), e))?;
+    network_isolation()?;
+
+    let dir = env::temp_dir().join(format!("rust-trainning-check-{}", process::id()));
+    let outcome = build_and_run(toolchain, topic.source, exercise, &source, &dir, limits)
+        .map_err(|e| format!("cannot check the solution: {}", e));
+    let _ = fs::remove_dir_all(&dir);
+
+    // Errors and panics point to the files of the temporary crate, they are named after the real ones.
+    let lesson = if is_whole_lesson(&source) {
+        solution.display().to_string()
+    } else {
+        format!("src/topics/{}.rs", topic.name)
+    };
+    let rename = |message: &String| {
+        message
+            .replace(
+                &dir.join("submission.rs").display().to_string(),
+                &solution.display().to_string(),
+            )
+            .replace(&dir.join("lesson.rs").display().to_string(), &lesson)
+    };
+    outcome.map(|mut outcome| {
+        outcome.verdict = match &outcome.verdict {
+            Verdict::Failed(message) => Verdict::Failed(rename(message)),
+            Verdict::Panicked(message) => Verdict::Panicked(rename(message)),
+            Verdict::DoesNotCompile(errors) => Verdict::DoesNotCompile(rename(errors)),
+            other => other.clone(),
+        };
+        outcome
+    })
+}
+
+fn build_and_run(
+    toolchain: &Toolchain,
+    lesson: &str,
+    exercise: &'static Example,
+    solution: &str,
+    dir: &Path,
+    limits: Limits,
+) -> io::Result<Outcome> {
+    fs::create_dir_all(dir)?;
+    if is_whole_lesson(solution) {
+        fs::write(dir.join("lesson.rs"), solution)?;
+        fs::write(dir.join("submission.rs"), "")?;
+    } else {
+        fs::write(
+            dir.join("lesson.rs"),
+            splice(lesson, solution, "submission.rs"),
+        )?;
+        fs::write(dir.join("submission.rs"), solution)?;
+    }
+    fs::write(dir.join("main.rs"), crate_root(exercise))?;
+
+    let binary = dir.join("solution");
+    let mut rustc = Command::new(&toolchain.rustc);
+    rustc
+        .args(["--edition", "2021", "--crate-type", "bin", "-o"])
+        .arg(&binary)
+        .arg(dir.join("main.rs"))
+        .arg("-L")
+        .arg(format!("dependency={}", toolchain.deps.display()));
+    for name in toolchain.externs()? {
+        rustc.args(["--extern", &name]);
+    }
+    let compiled = rustc.output()?;
+    if !compiled.status.success() {
+        return Ok(Outcome {
+            verdict: Verdict::DoesNotCompile(
+                String::from_utf8_lossy(&compiled.stderr).into_owned(),
+            ),
+            stdout: String::new(),
+            duration: Duration::ZERO,
+        });
+    }
+
+    run(&binary, limits)
+}
+
+fn run(binary: &Path, limits: Limits) -> io::Result<Outcome> {
+    // `unshare` and `sh` both exec the next program, so the child is the solution itself once started.
+    let script = format!("ulimit -v {} && exec \"$0\"", limits.memory_mb * 1024);
+    let start = Instant::now();
+    let mut child = Command::new("unshare")
+        .args(["-rn", "sh", "-c", &script])
+        .arg(binary)
+        .env("RUST_BACKTRACE", "0")
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // Read both pipes while waiting, a chatty solution would block on a full pipe otherwise.
+    let stdout = drain(child.stdout.take());
+    let stderr = drain(child.stderr.take());
+    let status = wait_for(&mut child, limits.timeout)?;
+    let duration = start.elapsed();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let verdict = match status {
+        None => Verdict::TimedOut,
+        Some(status) if status.success() => Verdict::Passed,
+        // The allocator aborts when it gets nothing from the system.
+        Some(_) if stderr.contains("memory allocation of") => Verdict::OutOfMemory,
+        Some(status) if !stderr.contains(" panicked at ") => Verdict::Crashed(crash(status)),
+        Some(_) => {
+            let message = report::panic_message(&stderr);
+            let location = report::panic_location(&stderr).unwrap_or_default();
+            // The locations point to the files of the temporary crate, which are still there.
+            if harness::is_assertion(location, |file| fs::read_to_string(file).ok()) {
+                Verdict::Failed(message)
+            } else {
+                Verdict::Panicked(message)
+            }
+        }
+    };
+
+    Ok(Outcome {
+        verdict,
+        stdout,
+        duration,
+    })
+}
+
+#[cfg(unix)]
+fn crash(status: ExitStatus) -> String {
+    match status.signal() {
+        Some(signal) => format!("killed by signal {}", signal),
+        None => format!("exited with {}", status),
+    }
+}
+
+#[cfg(not(unix))]
+fn crash(status: ExitStatus) -> String {
+    format!("exited with {}", status)
+}
+
+fn drain(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<String> {
+    thread::spawn(move || {
+        let mut text = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut text);
+        }
+        String::from_utf8_lossy(&text).into_owned()
+    })
+}
+
+// `None` if the child had to be killed.
+fn wait_for(child: &mut Child, timeout: Duration) -> io::Result<Option<process::ExitStatus>> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(status));
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+}
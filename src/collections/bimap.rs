@@ -0,0 +1,334 @@
+// # BiMap
+//
+// A one-to-one map, looked up from either side: two `HashMap`s, `L -> R` and `R -> L`, kept as mirrors of
+// each other. Every left value has one right value and every right value one left value, so each pair is
+// stored twice and both sides are `Clone`.
+//
+//   let ranks: BiMap<&str, u32> = [("Chinese Team", 1), ("France Team", 2)].into_iter().collect();
+//   ranks.get_by_left("France Team") -> Some(&2)
+//   ranks.get_by_right(&1)           -> Some(&"Chinese Team")
+//
+// Inserting a pair where one side is already taken would break that, so there are two ways to insert:
+// `insert` pushes the pairs in the way out and returns them, `try_insert` leaves the map alone and gives the
+// pair back. The entries of one side refuse, the same way, a value the other side already has.
+// Values can't be changed in place, the other map would not follow: remove the pair and insert a new one.
+
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap, RandomState};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+
+pub struct BiMap<L, R, S = RandomState> {
+    left: HashMap<L, R, S>,
+    right: HashMap<R, L, S>,
+}
+
+impl<L, R> BiMap<L, R, RandomState> {
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<L, R, S: Clone> BiMap<L, R, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            left: HashMap::with_hasher(hasher.clone()),
+            right: HashMap::with_hasher(hasher),
+        }
+    }
+}
+
+impl<L, R, S> BiMap<L, R, S> {
+    pub fn len(&self) -> usize {
+        self.left.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.left.is_empty()
+    }
+
+    pub fn iter(&self) -> hash_map::Iter<'_, L, R> {
+        self.left.iter()
+    }
+
+    pub fn left_values(&self) -> hash_map::Keys<'_, L, R> {
+        self.left.keys()
+    }
+
+    pub fn right_values(&self) -> hash_map::Keys<'_, R, L> {
+        self.right.keys()
+    }
+
+    pub fn clear(&mut self) {
+        self.left.clear();
+        self.right.clear();
+    }
+}
+
+impl<L, R, S> BiMap<L, R, S>
+where
+    L: Hash + Eq + Clone,
+    R: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    pub fn get_by_left<Q>(&self, left: &Q) -> Option<&R>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.left.get(left)
+    }
+
+    pub fn get_by_right<Q>(&self, right: &Q) -> Option<&L>
+    where
+        R: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.right.get(right)
+    }
+
+    pub fn contains_left<Q>(&self, left: &Q) -> bool
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.left.contains_key(left)
+    }
+
+    pub fn contains_right<Q>(&self, right: &Q) -> bool
+    where
+        R: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.right.contains_key(right)
+    }
+
+    // Inserts the pair, removing first the pairs that have `left` or `right` already: none, one, or two
+    // (one on each side). They are returned.
+    pub fn insert(&mut self, left: L, right: R) -> Vec<(L, R)> {
+        let mut removed = Vec::new();
+        removed.extend(self.remove_by_left(&left));
+        removed.extend(self.remove_by_right(&right));
+        self.right.insert(right.clone(), left.clone());
+        self.left.insert(left, right);
+        removed
+    }
+
+    // Inserts the pair if neither side is taken, else gives it back.
+    pub fn try_insert(&mut self, left: L, right: R) -> Result<(), (L, R)> {
+        if self.left.contains_key(&left) || self.right.contains_key(&right) {
+            return Err((left, right));
+        }
+        self.right.insert(right.clone(), left.clone());
+        self.left.insert(left, right);
+        Ok(())
+    }
+
+    pub fn remove_by_left<Q>(&mut self, left: &Q) -> Option<(L, R)>
+    where
+        L: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (left, right) = self.left.remove_entry(left)?;
+        self.right.remove(&right);
+        Some((left, right))
+    }
+
+    pub fn remove_by_right<Q>(&mut self, right: &Q) -> Option<(L, R)>
+    where
+        R: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (right, left) = self.right.remove_entry(right)?;
+        self.left.remove(&left);
+        Some((left, right))
+    }
+
+    // The entry of a left value: its right value if it has one.
+    pub fn entry_by_left(&mut self, left: L) -> Entry<'_, L, R, S> {
+        Entry::new(self.left.entry(left), &mut self.right)
+    }
+
+    // The entry of a right value: its left value if it has one.
+    pub fn entry_by_right(&mut self, right: R) -> Entry<'_, R, L, S> {
+        Entry::new(self.right.entry(right), &mut self.left)
+    }
+}
+
+// A value of one side (`A`, the key of the entry) and the value of the other side (`B`) it is paired with,
+// or not yet. `entry_by_left` gives an `Entry<L, R>`, `entry_by_right` an `Entry<R, L>`.
+pub enum Entry<'a, A, B, S> {
+    Occupied(OccupiedEntry<'a, A, B, S>),
+    Vacant(VacantEntry<'a, A, B, S>),
+}
+
+pub struct OccupiedEntry<'a, A, B, S> {
+    entry: hash_map::OccupiedEntry<'a, A, B>,
+    // The map of the other side.
+    mirror: &'a mut HashMap<B, A, S>,
+}
+
+pub struct VacantEntry<'a, A, B, S> {
+    entry: hash_map::VacantEntry<'a, A, B>,
+    mirror: &'a mut HashMap<B, A, S>,
+}
+
+impl<'a, A, B, S> Entry<'a, A, B, S>
+where
+    A: Hash + Eq + Clone,
+    B: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    fn new(entry: hash_map::Entry<'a, A, B>, mirror: &'a mut HashMap<B, A, S>) -> Self {
+        match entry {
+            hash_map::Entry::Occupied(entry) => Entry::Occupied(OccupiedEntry { entry, mirror }),
+            hash_map::Entry::Vacant(entry) => Entry::Vacant(VacantEntry { entry, mirror }),
+        }
+    }
+
+    // The value paired with the key, after pairing it with `default` if it had none. `Err(default)` when
+    // `default` is already paired with another key.
+    pub fn or_insert(self, default: B) -> Result<&'a B, B> {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with(self, default: impl FnOnce() -> B) -> Result<&'a B, B> {
+        match self {
+            Entry::Occupied(entry) => Ok(entry.into_ref()),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn key(&self) -> &A {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+impl<'a, A, B, S> OccupiedEntry<'a, A, B, S>
+where
+    A: Hash + Eq,
+    B: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn key(&self) -> &A {
+        self.entry.key()
+    }
+
+    pub fn get(&self) -> &B {
+        self.entry.get()
+    }
+
+    pub fn into_ref(self) -> &'a B {
+        self.entry.into_mut()
+    }
+
+    // Removes the pair from both sides.
+    pub fn remove(self) -> (A, B) {
+        self.mirror.remove(self.entry.get());
+        self.entry.remove_entry()
+    }
+}
+
+impl<'a, A, B, S> VacantEntry<'a, A, B, S>
+where
+    A: Hash + Eq + Clone,
+    B: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    pub fn key(&self) -> &A {
+        self.entry.key()
+    }
+
+    // Pairs the key with `value`, unless `value` is already paired with another key: then it is given back.
+    pub fn insert(self, value: B) -> Result<&'a B, B> {
+        if self.mirror.contains_key(&value) {
+            return Err(value);
+        }
+        self.mirror.insert(value.clone(), self.entry.key().clone());
+        Ok(self.entry.insert(value))
+    }
+}
+
+impl<'a, L, R, S> IntoIterator for &'a BiMap<L, R, S> {
+    type Item = (&'a L, &'a R);
+    type IntoIter = hash_map::Iter<'a, L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<L, R, S> IntoIterator for BiMap<L, R, S> {
+    type Item = (L, R);
+    type IntoIter = hash_map::IntoIter<L, R>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.left.into_iter()
+    }
+}
+
+impl<L, R, S> Default for BiMap<L, R, S>
+where
+    S: Default + Clone,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+// Each pair goes through `insert`: a later pair pushes out the earlier ones it shares a side with.
+impl<L, R, S> Extend<(L, R)> for BiMap<L, R, S>
+where
+    L: Hash + Eq + Clone,
+    R: Hash + Eq + Clone,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (L, R)>>(&mut self, pairs: I) {
+        for (left, right) in pairs {
+            self.insert(left, right);
+        }
+    }
+}
+
+impl<L, R, S> FromIterator<(L, R)> for BiMap<L, R, S>
+where
+    L: Hash + Eq + Clone,
+    R: Hash + Eq + Clone,
+    S: BuildHasher + Default + Clone,
+{
+    fn from_iter<I: IntoIterator<Item = (L, R)>>(pairs: I) -> Self {
+        let mut map = Self::default();
+        map.extend(pairs);
+        map
+    }
+}
+
+impl<L, R, const N: usize> From<[(L, R); N]> for BiMap<L, R, RandomState>
+where
+    L: Hash + Eq + Clone,
+    R: Hash + Eq + Clone,
+{
+    fn from(pairs: [(L, R); N]) -> Self {
+        pairs.into_iter().collect()
+    }
+}
+
+impl<L, R, S> PartialEq for BiMap<L, R, S>
+where
+    L: Hash + Eq,
+    R: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.left == other.left
+    }
+}
+
+impl<L: fmt::Debug, R: fmt::Debug, S> fmt::Debug for BiMap<L, R, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.left.iter()).finish()
+    }
+}
@@ -0,0 +1,230 @@
+// # Hashers
+//
+// A `HashMap` doesn't hash by itself: it asks its `BuildHasher` (the `S` of `HashMap<K, V, S>`) for a
+// fresh `Hasher`, feeds it the key through `Hash::hash`, and reads the `u64` at the end. Any pair of the
+// two traits can be plugged in with `HashMap::with_hasher` (std's or `collections::swiss`'s):
+//
+// - `SipHasher13` -> SipHash-1-3 with secret keys, std's default. Without the keys an attacker can't
+//                    choose keys that collide, which is what makes it resist HashDoS.
+// - `FnvHasher`   -> FNV-1a, one xor and one multiplication per byte. Fast on short keys, slow on long
+//                    ones, and anyone can compute collisions.
+// - `FxHasher`    -> the hasher of rustc (and Firefox): one rotation, xor and multiplication per 8 bytes.
+//                    The fastest on integers, and no protection at all.
+//
+// FNV and Fx have no state to pick, their `BuildHasher` is `BuildHasherDefault`.
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+// # SipHash-1-3
+//
+// The state is four `u64` initialized from the two keys. Every 8 bytes of input are xored in and mixed by
+// one `sip_round` (the "1"), and `finish` mixes three more times (the "3"). SipHash-2-4, the original,
+// mixes 2 and 4 times: std chose 1-3 for speed.
+#[derive(Debug, Clone)]
+pub struct SipHasher13 {
+    v0: u64,
+    v1: u64,
+    v2: u64,
+    v3: u64,
+    // Bytes waiting for a full word, and how many.
+    tail: u64,
+    ntail: usize,
+    length: usize,
+}
+
+impl SipHasher13 {
+    pub fn new_with_keys(k0: u64, k1: u64) -> Self {
+        Self {
+            v0: k0 ^ 0x736f_6d65_7073_6575,
+            v1: k1 ^ 0x646f_7261_6e64_6f6d,
+            v2: k0 ^ 0x6c79_6765_6e65_7261,
+            v3: k1 ^ 0x7465_6462_7974_6573,
+            tail: 0,
+            ntail: 0,
+            length: 0,
+        }
+    }
+
+    fn sip_round(&mut self) {
+        self.v0 = self.v0.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(13);
+        self.v1 ^= self.v0;
+        self.v0 = self.v0.rotate_left(32);
+        self.v2 = self.v2.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(16);
+        self.v3 ^= self.v2;
+        self.v0 = self.v0.wrapping_add(self.v3);
+        self.v3 = self.v3.rotate_left(21);
+        self.v3 ^= self.v0;
+        self.v2 = self.v2.wrapping_add(self.v1);
+        self.v1 = self.v1.rotate_left(17);
+        self.v1 ^= self.v2;
+        self.v2 = self.v2.rotate_left(32);
+    }
+
+    fn compress(&mut self, word: u64) {
+        self.v3 ^= word;
+        self.sip_round();
+        self.v0 ^= word;
+    }
+
+    fn push_byte(&mut self, byte: u8) {
+        self.tail |= (byte as u64) << (8 * self.ntail);
+        self.ntail += 1;
+        if self.ntail == 8 {
+            let word = self.tail;
+            self.compress(word);
+            self.tail = 0;
+            self.ntail = 0;
+        }
+    }
+}
+
+impl Hasher for SipHasher13 {
+    fn write(&mut self, mut bytes: &[u8]) {
+        self.length += bytes.len();
+        // Complete the waiting word first, then take whole words straight from the input.
+        while self.ntail != 0 && !bytes.is_empty() {
+            self.push_byte(bytes[0]);
+            bytes = &bytes[1..];
+        }
+        let mut words = bytes.chunks_exact(8);
+        for word in &mut words {
+            self.compress(u64::from_le_bytes(word.try_into().unwrap()));
+        }
+        for &byte in words.remainder() {
+            self.push_byte(byte);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        let mut state = self.clone();
+        let last = ((self.length as u64 & 0xff) << 56) | self.tail;
+        state.compress(last);
+        state.v2 ^= 0xff;
+        for _ in 0..3 {
+            state.sip_round();
+        }
+        state.v0 ^ state.v1 ^ state.v2 ^ state.v3
+    }
+}
+
+// Builds `SipHasher13`s that share two keys, picked at random by `new`.
+#[derive(Debug, Clone, Copy)]
+pub struct SipBuildHasher {
+    k0: u64,
+    k1: u64,
+}
+
+impl SipBuildHasher {
+    // The keys come from std's `RandomState`, which gets its own from the operating system.
+    pub fn new() -> Self {
+        let random = RandomState::new();
+        Self::with_keys(random.hash_one(0u8), random.hash_one(1u8))
+    }
+
+    pub fn with_keys(k0: u64, k1: u64) -> Self {
+        Self { k0, k1 }
+    }
+}
+
+impl Default for SipBuildHasher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BuildHasher for SipBuildHasher {
+    type Hasher = SipHasher13;
+
+    fn build_hasher(&self) -> SipHasher13 {
+        SipHasher13::new_with_keys(self.k0, self.k1)
+    }
+}
+
+// # FNV-1a
+//
+// Start from an offset basis, then for every byte: xor it in, multiply by a prime.
+#[derive(Debug, Clone, Copy)]
+pub struct FnvHasher(u64);
+
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+impl Default for FnvHasher {
+    fn default() -> Self {
+        FnvHasher(FNV_OFFSET_BASIS)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+pub type FnvBuildHasher = BuildHasherDefault<FnvHasher>;
+
+// # Fx
+//
+// For every word of input: rotate the state, xor the word in, multiply by a constant. Integers are one
+// word, so hashing a `u64` key is three instructions.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FxHasher(u64);
+
+const FX_SEED: u64 = 0x51_7c_c1_b7_27_22_0a_95;
+
+impl FxHasher {
+    fn add(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(FX_SEED);
+    }
+}
+
+impl Hasher for FxHasher {
+    fn write(&mut self, bytes: &[u8]) {
+        let mut chunks = bytes.chunks_exact(8);
+        for chunk in &mut chunks {
+            self.add(u64::from_le_bytes(chunk.try_into().unwrap()));
+        }
+        let rest = chunks.remainder();
+        if !rest.is_empty() {
+            let mut word = [0; 8];
+            word[..rest.len()].copy_from_slice(rest);
+            self.add(u64::from_le_bytes(word));
+        }
+    }
+
+    fn write_u8(&mut self, n: u8) {
+        self.add(n as u64);
+    }
+
+    fn write_u16(&mut self, n: u16) {
+        self.add(n as u64);
+    }
+
+    fn write_u32(&mut self, n: u32) {
+        self.add(n as u64);
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.add(n);
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.add(n as u64);
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
+
+pub type FxBuildHasher = BuildHasherDefault<FxHasher>;
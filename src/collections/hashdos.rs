@@ -0,0 +1,188 @@
+// # HashDoS
+//
+// A hash table is fast because keys spread over its buckets. Whoever picks the keys (the names in a form,
+// the fields of a JSON body) and knows the hasher can pick keys that all land in the same place: every
+// insert then walks past all the keys before it, and `n` inserts cost `n²`. That is HashDoS.
+//
+// FNV-1a has no secret, and it is easy to attack for one more reason: the low `n` bits of its state only
+// depend on the low `n` bits of the state before and on the input. So when two blocks of bytes `a` and
+// `b` give the same low 32 bits from some state, anything appended after them does too. Finding such a
+// pair takes a birthday search over about 2^16 blocks; chaining `k` pairs gives `2^k` keys, all different,
+// with the same low 32 bits of hash (a multicollision):
+//
+//   a1 a2 a3 ... ak
+//   a1 a2 a3 ... bk
+//   ...
+//   b1 b2 b3 ... bk
+//
+// A SwissTable (std's and `collections::swiss`) starts the search for a key at `hash & (buckets - 1)`: up
+// to 2^32 buckets, these keys all start at the same bucket. The same keys hashed with SipHash and a secret
+// key spread like any others.
+//
+// `attack` inserts keys into std's `HashMap` to time it, and into `collections::swiss::HashMap` to
+// measure how far lookups go (std doesn't tell). `cargo bench --bench hashdos` runs it.
+
+use std::collections::HashMap as StdMap;
+use std::fmt;
+use std::hash::{BuildHasher, Hasher};
+use std::time::{Duration, Instant};
+
+use super::hash::FnvHasher;
+use super::swiss;
+
+// Bits of hash shared by the colliding keys.
+pub const COLLIDING_BITS: u32 = 32;
+
+const BLOCK: usize = 8;
+
+// `2^count_log2` different strings of `8 * count_log2` lowercase letters whose FNV-1a hashes (as `String`
+// keys of a map using `FnvHasher`) have the same low `COLLIDING_BITS` bits.
+pub fn fnv_colliding_keys(count_log2: u32) -> Vec<String> {
+    let mask = (1u64 << COLLIDING_BITS) - 1;
+    let mut state = FnvHasher::default();
+    let mut pairs = Vec::new();
+
+    for _ in 0..count_log2 {
+        // Birthday search: blocks by the low bits of the state they lead to, until two meet.
+        let mut seen: StdMap<u64, [u8; BLOCK]> = StdMap::new();
+        let (a, b) = (0..)
+            .map(block)
+            .find_map(|candidate| {
+                let mut after = state;
+                after.write(&candidate);
+                let low = after.finish() & mask;
+                seen.insert(low, candidate)
+                    .filter(|&other| other != candidate)
+                    .map(|other| (other, candidate))
+            })
+            .expect("blocks run out before a collision");
+        state.write(&a);
+        pairs.push((a, b));
+    }
+
+    (0..1usize << count_log2)
+        .map(|choice| {
+            pairs
+                .iter()
+                .enumerate()
+                .flat_map(|(i, (a, b))| if choice >> i & 1 == 0 { a } else { b })
+                .map(|&byte| byte as char)
+                .collect()
+        })
+        .collect()
+}
+
+// The `n`th block of 8 lowercase letters. They are scattered (splitmix64): counting in base 26 would
+// change the first letters only, and short inputs that differ in a few bytes never collide, as every step
+// of FNV-1a is a bijection.
+fn block(n: u64) -> [u8; BLOCK] {
+    let mut x = n.wrapping_mul(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^= x >> 31;
+    let mut block = [b'a'; BLOCK];
+    for byte in &mut block {
+        *byte += (x % 26) as u8;
+        x /= 26;
+    }
+    block
+}
+
+// Keys of the same shape as `fnv_colliding_keys`, but random: what a fair user would send.
+pub fn random_keys(count_log2: u32, seed: u64) -> Vec<String> {
+    let mut state = seed | 1;
+    (0..1usize << count_log2)
+        .map(|_| {
+            (0..BLOCK * count_log2 as usize)
+                .map(|_| {
+                    state ^= state << 13;
+                    state ^= state >> 7;
+                    state ^= state << 17;
+                    (b'a' + (state % 26) as u8) as char
+                })
+                .collect()
+        })
+        .collect()
+}
+
+// What inserting a set of keys did to a map using one hasher.
+#[derive(Debug, Clone)]
+pub struct Attack {
+    pub hasher: &'static str,
+    pub keys: usize,
+    // Inserting all the keys into std's `HashMap`.
+    pub insert: Duration,
+    // `collections::swiss::HashMap::probe_lengths` once all the keys are in.
+    pub probe_lengths: Vec<usize>,
+}
+
+pub fn attack<S: BuildHasher + Clone>(hasher: &'static str, keys: &[String], build: S) -> Attack {
+    let mut map = StdMap::with_hasher(build.clone());
+    let start = Instant::now();
+    for key in keys {
+        map.insert(key.as_str(), ());
+    }
+    let insert = start.elapsed();
+
+    let mut swiss = swiss::HashMap::with_hasher(build);
+    for key in keys {
+        swiss.insert(key.as_str(), ());
+    }
+
+    Attack {
+        hasher,
+        keys: keys.len(),
+        insert,
+        probe_lengths: swiss.probe_lengths(),
+    }
+}
+
+impl Attack {
+    pub fn ns_per_insert(&self) -> f64 {
+        self.insert.as_nanos() as f64 / self.keys as f64
+    }
+
+    // The most groups a lookup visits.
+    pub fn longest_probe(&self) -> usize {
+        self.probe_lengths.len()
+    }
+
+    // Groups visited by a lookup of a key, on average over the keys.
+    pub fn mean_probe(&self) -> f64 {
+        let total: usize = (0..)
+            .zip(&self.probe_lengths)
+            .map(|(groups, count)| (groups + 1) * count)
+            .sum();
+        total as f64 / self.keys as f64
+    }
+}
+
+// The probe lengths by powers of two: `1`, `2`, `3-4`, `5-8`, ... groups.
+impl fmt::Display for Attack {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{}: {} keys, {:.1} ns per insert, {:.2} groups per lookup, {} at most",
+            self.hasher,
+            self.keys,
+            self.ns_per_insert(),
+            self.mean_probe(),
+            self.longest_probe()
+        )?;
+        let (mut low, mut high) = (1, 1);
+        while low <= self.longest_probe() {
+            let count: usize = self.probe_lengths[low - 1..high.min(self.longest_probe())]
+                .iter()
+                .sum();
+            let range = if low == high {
+                low.to_string()
+            } else {
+                format!("{}-{}", low, high)
+            };
+            let bar = "#".repeat((count * 50).div_ceil(self.keys));
+            writeln!(f, "  {:>11} groups  {:>7}  {}", range, count, bar)?;
+            (low, high) = (high + 1, high * 2);
+        }
+        Ok(())
+    }
+}
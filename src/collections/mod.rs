@@ -0,0 +1,18 @@
+// # Collections
+//
+// Teaching versions of the collections that the lessons use from `std`, written to be read: how they lay
+// out their data and what each operation costs. And two maps std doesn't have, built on its `HashMap`.
+//
+// - `swiss`    -> `HashMap`, the SwissTable design behind `std::collections::HashMap`
+// - `hash`     -> hashers to plug into either map: SipHash-1-3, FNV-1a and Fx
+// - `hashdos`  -> colliding keys for FNV-1a, and what they do to a map compared with SipHash
+// - `multimap` -> `MultiMap`, a key with many values, on top of std's `HashMap`
+// - `bimap`    -> `BiMap`, a one-to-one map looked up from either side
+// - `tracing_vec` -> `TracingVec`, a `Vec` that records each change of its capacity
+
+pub mod bimap;
+pub mod hash;
+pub mod hashdos;
+pub mod multimap;
+pub mod swiss;
+pub mod tracing_vec;
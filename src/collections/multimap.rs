@@ -0,0 +1,378 @@
+// # MultiMap
+//
+// A key with any number of values: `HashMap<K, Vec<V>>` behind a map-like API, so that no key is ever left
+// with an empty `Vec` and `len` counts values, not keys.
+//
+//   let teams: MultiMap<&str, &str> = [("Norway", "Einar"), ("Denmark", "Olaf"), ("Norway", "Harald")]
+//       .into_iter()
+//       .collect();
+//   teams.get_all("Norway")   -> "Einar", "Harald", in the order they were inserted
+//
+// Collecting groups the values by key, where collecting into a `HashMap` keeps the last value of each key.
+// The values of a key are handed out as slices: their order can be seen and changed, not their number,
+// which only changes through `insert`, `remove` and the entries.
+
+use std::borrow::Borrow;
+use std::collections::hash_map::{self, HashMap, RandomState};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::ops::Index;
+use std::slice;
+
+pub struct MultiMap<K, V, S = RandomState> {
+    map: HashMap<K, Vec<V>, S>,
+    // Values, over all the keys.
+    len: usize,
+}
+
+impl<K, V> MultiMap<K, V, RandomState> {
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+}
+
+impl<K, V, S> MultiMap<K, V, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self {
+            map: HashMap::with_hasher(hasher),
+            len: 0,
+        }
+    }
+
+    // Values, counting each one: `[("a", 1), ("a", 2)]` is 2.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Different keys: `[("a", 1), ("a", 2)]` is 1.
+    pub fn keys_len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.map.keys()
+    }
+
+    // Every key with every one of its values.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            groups: self.map.iter(),
+            current: None,
+            left: self.len,
+        }
+    }
+
+    // Every key once, with all its values.
+    pub fn groups(&self) -> impl Iterator<Item = (&K, &[V])> {
+        self.map
+            .iter()
+            .map(|(key, values)| (key, values.as_slice()))
+    }
+
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.len = 0;
+    }
+}
+
+impl<K, V, S> MultiMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    // Adds a value to the ones of `key`, after them.
+    pub fn insert(&mut self, key: K, value: V) {
+        self.entry(key).push(value);
+    }
+
+    // The values of `key`, oldest first: none when the key isn't there.
+    pub fn get_all<Q>(&self, key: &Q) -> slice::Iter<'_, V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map_or([].iter(), |values| values.iter())
+    }
+
+    pub fn get_all_mut<Q>(&mut self, key: &Q) -> slice::IterMut<'_, V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map
+            .get_mut(key)
+            .map_or([].iter_mut(), |values| values.iter_mut())
+    }
+
+    // The first value of `key`.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.get(key).map(|values| &values[0])
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.map.contains_key(key)
+    }
+
+    // Removes `key` with all its values.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<Vec<V>>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let values = self.map.remove(key)?;
+        self.len -= values.len();
+        Some(values)
+    }
+
+    // Keeps the values for which `f` returns true, and the keys that still have one.
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &V) -> bool) {
+        let len = &mut self.len;
+        self.map.retain(|key, values| {
+            let before = values.len();
+            values.retain(|value| f(key, value));
+            *len -= before - values.len();
+            !values.is_empty()
+        });
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V> {
+        match self.map.entry(key) {
+            hash_map::Entry::Occupied(entry) => Entry::Occupied(OccupiedEntry {
+                entry,
+                len: &mut self.len,
+            }),
+            hash_map::Entry::Vacant(entry) => Entry::Vacant(VacantEntry {
+                entry,
+                len: &mut self.len,
+            }),
+        }
+    }
+}
+
+// A key of a `MultiMap`, with values or not yet.
+pub enum Entry<'a, K, V> {
+    Occupied(OccupiedEntry<'a, K, V>),
+    Vacant(VacantEntry<'a, K, V>),
+}
+
+pub struct OccupiedEntry<'a, K, V> {
+    entry: hash_map::OccupiedEntry<'a, K, Vec<V>>,
+    len: &'a mut usize,
+}
+
+pub struct VacantEntry<'a, K, V> {
+    entry: hash_map::VacantEntry<'a, K, Vec<V>>,
+    len: &'a mut usize,
+}
+
+impl<'a, K, V> Entry<'a, K, V> {
+    // Adds `value` after the values of the key, if it has any, and returns them all.
+    pub fn push(self, value: V) -> &'a mut [V] {
+        match self {
+            Entry::Occupied(mut entry) => {
+                entry.push(value);
+                entry.into_mut()
+            }
+            Entry::Vacant(entry) => entry.insert(value),
+        }
+    }
+
+    // Gives the key its first value if it has none, and returns all its values.
+    pub fn or_insert(self, default: V) -> &'a mut [V] {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut [V] {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn and_modify(mut self, f: impl FnOnce(&mut [V])) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => entry.key(),
+        }
+    }
+}
+
+impl<'a, K, V> OccupiedEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        self.entry.key()
+    }
+
+    pub fn get(&self) -> &[V] {
+        self.entry.get()
+    }
+
+    pub fn get_mut(&mut self) -> &mut [V] {
+        self.entry.get_mut()
+    }
+
+    pub fn into_mut(self) -> &'a mut [V] {
+        self.entry.into_mut()
+    }
+
+    pub fn push(&mut self, value: V) {
+        self.entry.get_mut().push(value);
+        *self.len += 1;
+    }
+
+    // Removes the key and all its values.
+    pub fn remove(self) -> Vec<V> {
+        let values = self.entry.remove();
+        *self.len -= values.len();
+        values
+    }
+}
+
+impl<'a, K, V> VacantEntry<'a, K, V> {
+    pub fn key(&self) -> &K {
+        self.entry.key()
+    }
+
+    pub fn insert(self, value: V) -> &'a mut [V] {
+        *self.len += 1;
+        self.entry.insert(vec![value])
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    groups: hash_map::Iter<'a, K, Vec<V>>,
+    current: Option<(&'a K, slice::Iter<'a, V>)>,
+    left: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((key, values)) = &mut self.current {
+                if let Some(value) = values.next() {
+                    self.left -= 1;
+                    return Some((*key, value));
+                }
+            }
+            let (key, values) = self.groups.next()?;
+            self.current = Some((key, values.iter()));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a MultiMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+// Every key with all its values.
+impl<K, V, S> IntoIterator for MultiMap<K, V, S> {
+    type Item = (K, Vec<V>);
+    type IntoIter = hash_map::IntoIter<K, Vec<V>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.map.into_iter()
+    }
+}
+
+impl<K, V, S> Default for MultiMap<K, V, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for MultiMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, entries: I) {
+        for (key, value) in entries {
+            self.insert(key, value);
+        }
+    }
+}
+
+// Groups the values by key, in the order they come.
+impl<K, V, S> FromIterator<(K, V)> for MultiMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        let mut map = Self::default();
+        map.extend(entries);
+        map
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for MultiMap<K, V, RandomState>
+where
+    K: Hash + Eq,
+{
+    fn from(entries: [(K, V); N]) -> Self {
+        entries.into_iter().collect()
+    }
+}
+
+// All the values of a key. Panics if the key isn't there, like `HashMap`'s.
+impl<K, Q, V, S> Index<&Q> for MultiMap<K, V, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = [V];
+
+    fn index(&self, key: &Q) -> &[V] {
+        self.map.get(key).expect("key not found in the MultiMap")
+    }
+}
+
+impl<K, V, S> PartialEq for MultiMap<K, V, S>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.map == other.map
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S> fmt::Debug for MultiMap<K, V, S> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.groups()).finish()
+    }
+}
@@ -0,0 +1,646 @@
+// # SwissTable
+//
+// `std::collections::HashMap` is a port of Google's SwissTable. This is a small version of it, with the
+// same layout and the same probing, without the SIMD and the unsafe code.
+//
+// The table is an array of slots (`buckets`, a power of two) and, next to it, one control byte per slot:
+//
+//   EMPTY     0b1111_1111   never used: a lookup that meets one can stop
+//   DELETED   0b1000_0000   a tombstone: used once, removed since, a lookup must go past it
+//   FULL      0b0xxx_xxxx   the slot holds an entry, `xxx_xxxx` are 7 bits of its key's hash (`h2`)
+//
+// The other bits of the hash (`h1`) pick the slot where the search starts. Control bytes are read by
+// `Group`s of 8, as one `u64`: comparing the 8 bytes with `h2` at once finds the few slots worth comparing
+// keys with, so most misses never touch a key. std compares 16 bytes with one SSE2 instruction instead.
+// When a group has no match and no EMPTY, the search moves to another group, further and further
+// (`pos + 8`, `pos + 8 + 16`, ...): the triangular probing that visits every group of the table.
+//
+// Removing an entry leaves a tombstone when some lookup may have gone past the slot (its group was full
+// when they were inserted), else the slot is EMPTY again. The table grows (doubles) when it would be more
+// than 7/8 full, counting tombstones; when tombstones are most of it, it is rebuilt at the same size.
+
+use std::borrow::Borrow;
+use std::collections::hash_map::RandomState;
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+use std::mem;
+use std::ops::Index;
+
+const EMPTY: u8 = 0b1111_1111;
+const DELETED: u8 = 0b1000_0000;
+
+const GROUP: usize = 8;
+
+// 8 control bytes, the first one in the lowest byte.
+#[derive(Clone, Copy)]
+struct Group(u64);
+
+const LOW_BITS: u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS: u64 = 0x8080_8080_8080_8080;
+
+impl Group {
+    fn load(ctrl: &[u8], pos: usize) -> Self {
+        let bytes: [u8; GROUP] = ctrl[pos..pos + GROUP].try_into().unwrap();
+        Group(u64::from_le_bytes(bytes))
+    }
+
+    // The bytes equal to `byte`. A byte right after a match may come out as a false positive: the keys are
+    // compared anyway.
+    fn matching(self, byte: u8) -> BitMask {
+        let x = self.0 ^ (LOW_BITS * byte as u64);
+        BitMask(x.wrapping_sub(LOW_BITS) & !x & HIGH_BITS)
+    }
+
+    // EMPTY is the only control byte with its two highest bits set.
+    fn empty(self) -> BitMask {
+        BitMask(self.0 & (self.0 << 1) & HIGH_BITS)
+    }
+
+    fn empty_or_deleted(self) -> BitMask {
+        BitMask(self.0 & HIGH_BITS)
+    }
+}
+
+// The high bit of each selected byte of a group.
+#[derive(Clone, Copy)]
+struct BitMask(u64);
+
+impl BitMask {
+    fn any(self) -> bool {
+        self.0 != 0
+    }
+
+    fn first(self) -> Option<usize> {
+        self.any().then(|| self.0.trailing_zeros() as usize / GROUP)
+    }
+
+    // How many bytes are not selected at the start and at the end of the group.
+    fn leading(self) -> usize {
+        self.0.trailing_zeros() as usize / GROUP
+    }
+
+    fn trailing(self) -> usize {
+        self.0.leading_zeros() as usize / GROUP
+    }
+}
+
+impl Iterator for BitMask {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize> {
+        let index = self.first()?;
+        self.0 &= self.0 - 1;
+        Some(index)
+    }
+}
+
+// The groups to visit for a hash.
+struct Probe {
+    pos: usize,
+    stride: usize,
+    mask: usize,
+}
+
+impl Probe {
+    fn next(&mut self) {
+        self.stride += GROUP;
+        self.pos = (self.pos + self.stride) & self.mask;
+    }
+}
+
+fn h1(hash: u64) -> usize {
+    hash as usize
+}
+
+// The 7 highest bits: `h1` uses the lowest ones, the two should not be related.
+fn h2(hash: u64) -> u8 {
+    (hash >> 57) as u8
+}
+
+// The number of buckets for `capacity` entries, at most 7/8 full.
+fn buckets_for(capacity: usize) -> usize {
+    (capacity * 8).div_ceil(7).next_power_of_two().max(GROUP)
+}
+
+pub struct HashMap<K, V, S = RandomState> {
+    // One byte per bucket, then a copy of the first `GROUP` ones: a group starting at any bucket can be
+    // read without wrapping around.
+    ctrl: Vec<u8>,
+    slots: Vec<Option<(K, V)>>,
+    items: usize,
+    // How many more entries fit before growing, EMPTY slots that may still be used.
+    growth_left: usize,
+    hasher: S,
+}
+
+impl<K, V> HashMap<K, V, RandomState> {
+    pub fn new() -> Self {
+        Self::with_hasher(RandomState::new())
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_capacity_and_hasher(capacity, RandomState::new())
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S> {
+    pub fn with_hasher(hasher: S) -> Self {
+        Self::with_capacity_and_hasher(0, hasher)
+    }
+
+    pub fn with_capacity_and_hasher(capacity: usize, hasher: S) -> Self {
+        let buckets = buckets_for(capacity);
+        Self {
+            ctrl: vec![EMPTY; buckets + GROUP],
+            slots: (0..buckets).map(|_| None).collect(),
+            items: 0,
+            growth_left: buckets / 8 * 7,
+            hasher,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items == 0
+    }
+
+    // How many entries fit before the table grows.
+    pub fn capacity(&self) -> usize {
+        self.buckets() / 8 * 7
+    }
+
+    pub fn buckets(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn tombstones(&self) -> usize {
+        self.ctrl[..self.buckets()]
+            .iter()
+            .filter(|&&byte| byte == DELETED)
+            .count()
+    }
+
+    pub fn hasher(&self) -> &S {
+        &self.hasher
+    }
+
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: self.slots.iter(),
+            left: self.items,
+        }
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.iter().map(|(key, _)| key)
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &V> {
+        self.iter().map(|(_, value)| value)
+    }
+
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> {
+        self.slots.iter_mut().flatten().map(|(_, value)| value)
+    }
+
+    pub fn clear(&mut self) {
+        self.ctrl.fill(EMPTY);
+        self.slots.iter_mut().for_each(|slot| *slot = None);
+        self.items = 0;
+        self.growth_left = self.capacity();
+    }
+
+    // The control bytes, for the lessons: `.` EMPTY, `x` DELETED, and `h2` in hex for a FULL slot.
+    pub fn layout(&self) -> String {
+        self.ctrl[..self.buckets()]
+            .iter()
+            .map(|&byte| match byte {
+                EMPTY => " .".to_string(),
+                DELETED => " x".to_string(),
+                h2 => format!("{:02x}", h2),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    fn mask(&self) -> usize {
+        self.buckets() - 1
+    }
+
+    fn probe(&self, hash: u64) -> Probe {
+        Probe {
+            pos: h1(hash) & self.mask(),
+            stride: 0,
+            mask: self.mask(),
+        }
+    }
+
+    // Sets a control byte and its copy after the end.
+    fn set_ctrl(&mut self, index: usize, byte: u8) {
+        let copy = (index.wrapping_sub(GROUP) & self.mask()) + GROUP;
+        self.ctrl[index] = byte;
+        self.ctrl[copy] = byte;
+    }
+
+    // The first EMPTY or DELETED slot on the way of `hash`. There always is one: the table is never full.
+    fn find_insert_slot(&self, hash: u64) -> usize {
+        let mut probe = self.probe(hash);
+        loop {
+            let group = Group::load(&self.ctrl, probe.pos);
+            if let Some(bit) = group.empty_or_deleted().first() {
+                return (probe.pos + bit) & self.mask();
+            }
+            probe.next();
+        }
+    }
+
+    fn entry_at(&self, index: usize) -> (&K, &V) {
+        let (key, value) = self.slots[index].as_ref().expect("a full slot");
+        (key, value)
+    }
+
+    fn value_at_mut(&mut self, index: usize) -> &mut V {
+        &mut self.slots[index].as_mut().expect("a full slot").1
+    }
+
+    fn remove_at(&mut self, index: usize) -> (K, V) {
+        // If a group around the slot was never full, no lookup went past the slot: it can be EMPTY again.
+        let before = Group::load(&self.ctrl, index.wrapping_sub(GROUP) & self.mask()).empty();
+        let after = Group::load(&self.ctrl, index).empty();
+        let byte = if before.trailing() + after.leading() >= GROUP {
+            DELETED
+        } else {
+            self.growth_left += 1;
+            EMPTY
+        };
+        self.set_ctrl(index, byte);
+        self.items -= 1;
+        self.slots[index].take().expect("a full slot")
+    }
+}
+
+impl<K, V, S> HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let hash = self.hasher.hash_one(&key);
+        match self.find(hash, &key) {
+            Some(index) => Some(mem::replace(self.value_at_mut(index), value)),
+            None => {
+                self.insert_new(hash, key, value);
+                None
+            }
+        }
+    }
+
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hasher.hash_one(key), key)?;
+        Some(self.entry_at(index).1)
+    }
+
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hasher.hash_one(key), key)?;
+        Some(self.value_at_mut(index))
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.get(key).is_some()
+    }
+
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let index = self.find(self.hasher.hash_one(key), key)?;
+        Some(self.remove_at(index).1)
+    }
+
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, S> {
+        let hash = self.hasher.hash_one(&key);
+        match self.find(hash, &key) {
+            Some(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            None => Entry::Vacant(VacantEntry {
+                map: self,
+                hash,
+                key,
+            }),
+        }
+    }
+
+    // Makes room for `additional` more entries without growing.
+    pub fn reserve(&mut self, additional: usize) {
+        if additional > self.growth_left {
+            self.resize(self.items + additional);
+        }
+    }
+
+    // How far lookups go: `lengths[n]` entries are found in the `n + 1`th group of their probe sequence.
+    // With a good hasher almost all are in the first one.
+    pub fn probe_lengths(&self) -> Vec<usize> {
+        let mut lengths = Vec::new();
+        for (index, slot) in self.slots.iter().enumerate() {
+            let Some((key, _)) = slot else { continue };
+            let mut probe = self.probe(self.hasher.hash_one(key));
+            let mut groups = 0;
+            while index.wrapping_sub(probe.pos) & self.mask() >= GROUP {
+                probe.next();
+                groups += 1;
+            }
+            if lengths.len() <= groups {
+                lengths.resize(groups + 1, 0);
+            }
+            lengths[groups] += 1;
+        }
+        lengths
+    }
+
+    fn find<Q>(&self, hash: u64, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let mut probe = self.probe(hash);
+        loop {
+            let group = Group::load(&self.ctrl, probe.pos);
+            for bit in group.matching(h2(hash)) {
+                let index = (probe.pos + bit) & self.mask();
+                if let Some((candidate, _)) = &self.slots[index] {
+                    if candidate.borrow() == key {
+                        return Some(index);
+                    }
+                }
+            }
+            if group.empty().any() {
+                return None;
+            }
+            probe.next();
+        }
+    }
+
+    fn insert_new(&mut self, hash: u64, key: K, value: V) -> usize {
+        let mut index = self.find_insert_slot(hash);
+        // Reusing a tombstone doesn't take any room, an EMPTY slot does.
+        if self.growth_left == 0 && self.ctrl[index] == EMPTY {
+            self.resize(self.items + 1);
+            index = self.find_insert_slot(hash);
+        }
+        if self.ctrl[index] == EMPTY {
+            self.growth_left -= 1;
+        }
+        self.set_ctrl(index, h2(hash));
+        self.slots[index] = Some((key, value));
+        self.items += 1;
+        index
+    }
+
+    // Moves every entry to a new table: twice as big, or the same size when tombstones take half of it.
+    fn resize(&mut self, needed: usize) {
+        let buckets = if needed <= self.capacity() / 2 {
+            self.buckets()
+        } else {
+            buckets_for(needed.max(self.capacity() + 1))
+        };
+
+        let slots = mem::replace(&mut self.slots, (0..buckets).map(|_| None).collect());
+        self.ctrl = vec![EMPTY; buckets + GROUP];
+        self.growth_left = self.capacity();
+        for (key, value) in slots.into_iter().flatten() {
+            let hash = self.hasher.hash_one(&key);
+            let index = self.find_insert_slot(hash);
+            self.set_ctrl(index, h2(hash));
+            self.slots[index] = Some((key, value));
+            self.growth_left -= 1;
+        }
+    }
+}
+
+pub enum Entry<'a, K, V, S> {
+    Occupied(OccupiedEntry<'a, K, V, S>),
+    Vacant(VacantEntry<'a, K, V, S>),
+}
+
+pub struct OccupiedEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    index: usize,
+}
+
+pub struct VacantEntry<'a, K, V, S> {
+    map: &'a mut HashMap<K, V, S>,
+    hash: u64,
+    key: K,
+}
+
+impl<'a, K, V, S> Entry<'a, K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => entry.insert(default()),
+        }
+    }
+
+    pub fn or_default(self) -> &'a mut V
+    where
+        V: Default,
+    {
+        self.or_insert_with(V::default)
+    }
+
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Entry::Occupied(entry) = &mut self {
+            f(entry.get_mut());
+        }
+        self
+    }
+
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(entry) => entry.key(),
+            Entry::Vacant(entry) => &entry.key,
+        }
+    }
+}
+
+impl<'a, K, V, S> OccupiedEntry<'a, K, V, S> {
+    pub fn key(&self) -> &K {
+        self.map.entry_at(self.index).0
+    }
+
+    pub fn get(&self) -> &V {
+        self.map.entry_at(self.index).1
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        self.map.value_at_mut(self.index)
+    }
+
+    pub fn into_mut(self) -> &'a mut V {
+        self.map.value_at_mut(self.index)
+    }
+
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    pub fn remove(self) -> V {
+        self.map.remove_at(self.index).1
+    }
+}
+
+impl<'a, K, V, S> VacantEntry<'a, K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn insert(self, value: V) -> &'a mut V {
+        let index = self.map.insert_new(self.hash, self.key, value);
+        self.map.value_at_mut(index)
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    slots: std::slice::Iter<'a, Option<(K, V)>>,
+    left: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (key, value) = self.slots.by_ref().flatten().next()?;
+        self.left -= 1;
+        Some((key, value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.left, Some(self.left))
+    }
+}
+
+impl<'a, K, V, S> IntoIterator for &'a HashMap<K, V, S> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Iter<'a, K, V> {
+        self.iter()
+    }
+}
+
+impl<K, V, S> IntoIterator for HashMap<K, V, S> {
+    type Item = (K, V);
+    type IntoIter = std::iter::Flatten<std::vec::IntoIter<Option<(K, V)>>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.slots.into_iter().flatten()
+    }
+}
+
+impl<K, V, S> Default for HashMap<K, V, S>
+where
+    S: Default,
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<K, V, S> Extend<(K, V)> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher,
+{
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, entries: I) {
+        for (key, value) in entries {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, S> FromIterator<(K, V)> for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    S: BuildHasher + Default,
+{
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        let mut map = Self::default();
+        map.extend(entries);
+        map
+    }
+}
+
+impl<K, V, const N: usize> From<[(K, V); N]> for HashMap<K, V, RandomState>
+where
+    K: Hash + Eq,
+{
+    fn from(entries: [(K, V); N]) -> Self {
+        let mut map = Self::with_capacity(N);
+        map.extend(entries);
+        map
+    }
+}
+
+impl<K, Q, V, S> Index<&Q> for HashMap<K, V, S>
+where
+    K: Hash + Eq + Borrow<Q>,
+    Q: Hash + Eq + ?Sized,
+    S: BuildHasher,
+{
+    type Output = V;
+
+    fn index(&self, key: &Q) -> &V {
+        self.get(key).expect("no entry found for key")
+    }
+}
+
+impl<K, V, S> PartialEq for HashMap<K, V, S>
+where
+    K: Hash + Eq,
+    V: PartialEq,
+    S: BuildHasher,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| other.get(key) == Some(value))
+    }
+}
+
+impl<K, V, S> fmt::Debug for HashMap<K, V, S>
+where
+    K: fmt::Debug,
+    V: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,267 @@
+// # TracingVec
+//
+// A `Vec` that writes down every time its buffer changes size. A `Vec` is a pointer, a capacity and a
+// length: pushing past the capacity asks the allocator for a bigger buffer, which may extend the old one
+// where it is or hand out another one somewhere else, and then every element has to be copied over.
+// `TracingVec` compares the capacity and the pointer before and after each operation:
+//
+//   let mut v = TracingVec::new();
+//   for i in 0..5 { v.push(i) }
+//   v.history() -> push at len 0: capacity 0 -> 4
+//                  push at len 4: capacity 4 -> 8, and maybe `moved`
+//
+// Made `.logged()`, it also prints each one as it happens, without the capacities: `push at len 4:
+// reallocated`.
+//
+// The new capacities are std's growth policy (today: doubling, at least 4 small elements), which is not
+// part of `Vec`'s API and may change with the toolchain. Whether a reallocation moved the elements is up
+// to the allocator, and can change from one run to the next. What is promised: `Vec::with_capacity` (or
+// `reserve`) up front leaves nothing to trace.
+//
+// It reads like a `Vec` (it derefs to a slice: `v[i]`, `v.get(i)`, `v.iter()`), and the operations that
+// change the length are its own, so that none of them goes untraced.
+
+use std::fmt;
+use std::ops::{Deref, DerefMut, RangeBounds};
+use std::vec;
+
+// A change of capacity, and the operation that caused it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Growth {
+    pub operation: &'static str,
+    // Elements in the vector when the operation started.
+    pub len: usize,
+    pub old_capacity: usize,
+    pub new_capacity: usize,
+    // The elements were copied to a new buffer. Never the case with nothing to copy.
+    pub moved: bool,
+}
+
+// Without `moved`, which can change from one run to the next.
+impl fmt::Display for Growth {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{} at len {}: capacity {} -> {}",
+            self.operation, self.len, self.old_capacity, self.new_capacity
+        )
+    }
+}
+
+#[derive(Clone)]
+pub struct TracingVec<T> {
+    vec: Vec<T>,
+    history: Vec<Growth>,
+    // Print each `Growth` as it happens.
+    logged: bool,
+}
+
+impl<T> TracingVec<T> {
+    pub fn new() -> Self {
+        Vec::new().into()
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity).into()
+    }
+
+    // Prints when each change of capacity happens to stdout, on top of keeping it in the history. Only the
+    // operation and the length: the capacities and whether the elements moved are not the same on every
+    // toolchain and every run.
+    pub fn logged(mut self) -> Self {
+        self.logged = true;
+        self
+    }
+
+    // Every change of capacity so far, oldest first.
+    pub fn history(&self) -> &[Growth] {
+        &self.history
+    }
+
+    // How many times the elements were copied to a new buffer.
+    pub fn moves(&self) -> usize {
+        self.history.iter().filter(|growth| growth.moved).count()
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.vec.capacity()
+    }
+
+    pub fn as_vec(&self) -> &Vec<T> {
+        &self.vec
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        self.vec
+    }
+
+    pub fn push(&mut self, value: T) {
+        self.traced("push", |vec| vec.push(value));
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        self.traced("pop", Vec::pop)
+    }
+
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.traced("insert", |vec| vec.insert(index, value));
+    }
+
+    pub fn remove(&mut self, index: usize) -> T {
+        self.traced("remove", |vec| vec.remove(index))
+    }
+
+    // Draining never changes the capacity: the buffer stays, emptier.
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> vec::Drain<'_, T> {
+        self.vec.drain(range)
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        self.vec.truncate(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+
+    pub fn reserve(&mut self, additional: usize) {
+        self.traced("reserve", |vec| vec.reserve(additional));
+    }
+
+    pub fn shrink_to_fit(&mut self) {
+        self.traced("shrink_to_fit", Vec::shrink_to_fit);
+    }
+
+    // Runs `f` on the vector and records a `Growth` if the capacity changed.
+    fn traced<R>(&mut self, operation: &'static str, f: impl FnOnce(&mut Vec<T>) -> R) -> R {
+        let (len, old_capacity, old_ptr) = (self.vec.len(), self.vec.capacity(), self.vec.as_ptr());
+        let result = f(&mut self.vec);
+        if self.vec.capacity() != old_capacity {
+            let growth = Growth {
+                operation,
+                len,
+                old_capacity,
+                new_capacity: self.vec.capacity(),
+                moved: len > 0 && self.vec.as_ptr() != old_ptr,
+            };
+            if self.logged {
+                println!("{} at len {}: reallocated", operation, len);
+            }
+            self.history.push(growth);
+        }
+        result
+    }
+}
+
+// Not derived, which would require `T: Default`.
+impl<T> Default for TracingVec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Deref for TracingVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.vec
+    }
+}
+
+impl<T> DerefMut for TracingVec<T> {
+    fn deref_mut(&mut self) -> &mut [T] {
+        &mut self.vec
+    }
+}
+
+// The whole `extend` is one operation: `Vec` reserves what the iterator says it has, then grows as needed.
+impl<T> Extend<T> for TracingVec<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.traced("extend", |vec| vec.reserve(lower));
+        for value in iter {
+            self.traced("extend", |vec| vec.push(value));
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a> Extend<&'a T> for TracingVec<T> {
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T> From<Vec<T>> for TracingVec<T> {
+    fn from(vec: Vec<T>) -> Self {
+        Self {
+            vec,
+            history: Vec::new(),
+            logged: false,
+        }
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for TracingVec<T> {
+    fn from(array: [T; N]) -> Self {
+        Vec::from(array).into()
+    }
+}
+
+impl<T> FromIterator<T> for TracingVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+impl<T> IntoIterator for TracingVec<T> {
+    type Item = T;
+    type IntoIter = vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a TracingVec<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.vec.iter()
+    }
+}
+
+// Equal when the elements are: the history doesn't count.
+impl<T: PartialEq> PartialEq for TracingVec<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.vec == other.vec
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for TracingVec<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        &self.vec == other
+    }
+}
+
+impl<T: PartialEq> PartialEq<TracingVec<T>> for Vec<T> {
+    fn eq(&self, other: &TracingVec<T>) -> bool {
+        self == &other.vec
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq<[T; N]> for TracingVec<T> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.vec == other
+    }
+}
+
+// Like a `Vec`: the elements only.
+impl<T: fmt::Debug> fmt::Debug for TracingVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.vec.fmt(f)
+    }
+}
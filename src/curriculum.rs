@@ -0,0 +1,121 @@
+// # Curriculum
+//
+// The module prefixes (`aa_`, `ab_`, `b_`...) only order part of the lessons. The real order comes from
+// the prerequisites every topic declares in `topics::TOPICS` (`lifetimes` requires `b_borrowing`,
+// `trait_object` requires `traits`...), which must form a graph without cycles.
+//
+// The curriculum is a topological order of that graph: a topic comes after all of its prerequisites.
+// When several topics are ready at the same time the module name decides, so the prefixes still count.
+//
+// `curriculum` prints it, `curriculum --dot` renders the graph for Graphviz:
+//
+// cargo run -- curriculum --dot | dot -Tsvg > curriculum.svg
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write as _;
+
+use crate::registry::{self, Topic};
+
+// The topics of `topics` in curriculum order, or why there is none (unknown prerequisite, cycle).
+pub fn order(topics: &[Topic]) -> Result<Vec<&Topic>, String> {
+    let by_name: BTreeMap<&str, &Topic> = topics.iter().map(|topic| (topic.name, topic)).collect();
+
+    let mut missing = BTreeMap::new();
+    for topic in topics {
+        for &required in topic.requires {
+            if !by_name.contains_key(required) {
+                return Err(format!(
+                    "`{}` requires unknown topic `{}`",
+                    topic.name, required
+                ));
+            }
+        }
+        let requires: BTreeSet<&str> = topic.requires.iter().copied().collect();
+        missing.insert(topic.name, requires);
+    }
+
+    // Kahn's algorithm, always taking the first ready topic by name.
+    let mut order = Vec::with_capacity(topics.len());
+    while let Some(&next) = missing
+        .iter()
+        .find(|(_, requires)| requires.is_empty())
+        .map(|(name, _)| name)
+    {
+        missing.remove(next);
+        for requires in missing.values_mut() {
+            requires.remove(next);
+        }
+        order.push(by_name[next]);
+    }
+
+    if missing.is_empty() {
+        Ok(order)
+    } else {
+        Err(format!(
+            "prerequisites form a cycle: {}",
+            find_cycle(&missing).join(" -> ")
+        ))
+    }
+}
+
+// Every topic left has a missing prerequisite that is also left, so following them always loops.
+fn find_cycle<'a>(missing: &BTreeMap<&'a str, BTreeSet<&'a str>>) -> Vec<&'a str> {
+    let mut path: Vec<&str> = Vec::new();
+    let mut current = *missing.keys().next().expect("a cycle needs topics");
+
+    loop {
+        if let Some(start) = path.iter().position(|&name| name == current) {
+            let mut cycle = path.split_off(start);
+            cycle.push(current);
+            return cycle;
+        }
+        path.push(current);
+        current = missing[current]
+            .iter()
+            .next()
+            .expect("topic left without a prerequisite");
+    }
+}
+
+pub fn curriculum() -> Result<Vec<&'static Topic>, String> {
+    order(registry::topics())
+}
+
+pub fn dot(topics: &[Topic]) -> String {
+    let mut out = String::from("digraph curriculum {\n    rankdir=LR;\n    node [shape=box];\n\n");
+
+    for topic in topics {
+        let _ = writeln!(
+            out,
+            "    \"{}\" [label=\"{}\"];",
+            topic.name,
+            topic.title.replace('"', "\\\"")
+        );
+    }
+    out.push('\n');
+    for topic in topics {
+        for required in topic.requires {
+            let _ = writeln!(out, "    \"{}\" -> \"{}\";", required, topic.name);
+        }
+    }
+
+    out.push_str("}\n");
+    out
+}
+
+pub fn print(topics: &[&Topic]) {
+    let width = topics.iter().map(|t| t.name.len()).max().unwrap_or(0);
+    for (step, topic) in topics.iter().enumerate() {
+        let mut line = format!(
+            "{:>2}. {:width$}  {}",
+            step + 1,
+            topic.name,
+            topic.title,
+            width = width
+        );
+        if !topic.requires.is_empty() {
+            let _ = write!(line, " (after {})", topic.requires.join(", "));
+        }
+        println!("{}", line);
+    }
+}
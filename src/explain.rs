@@ -0,0 +1,131 @@
+// # Panic Explanations
+//
+// A panic message says what went wrong, not where to learn about it. Once `install`ed (by `run` and the
+// shell), a panic hook prints the usual message and then recognizes the common ones:
+//
+// - index out of bounds, integer overflow, division by zero, explicit `panic!` -> `error_handling`
+// - `unwrap` on `None`                                                         -> `gb_option_enum`
+// - `unwrap` on `Err`                                                          -> `result_class`
+// - slicing a string inside of a character                                     -> `string`
+//
+// It points to the lesson (and to the function that shows the case, if there is one) and prints the
+// lesson's opening comment block. `grade` prints the same explanation under a failed exercise.
+
+use std::panic;
+
+use crate::lesson;
+use crate::registry;
+
+pub struct Rule {
+    // What the learner is told the panic is: "this is {kind}".
+    pub kind: &'static str,
+    // Parts of the panic message that identify it.
+    patterns: &'static [&'static str],
+    pub topic: &'static str,
+    // The function of the lesson that shows this case.
+    pub item: Option<&'static str>,
+}
+
+pub const RULES: &[Rule] = &[
+    Rule {
+        kind: "an index out of bounds",
+        patterns: &[
+            "index out of bounds",
+            "range end index",
+            "range start index",
+        ],
+        topic: "error_handling",
+        item: Some("panic_example_02"),
+    },
+    Rule {
+        kind: "an integer overflow",
+        patterns: &["with overflow"],
+        topic: "error_handling",
+        item: Some("production_rate_per_hour"),
+    },
+    Rule {
+        kind: "a division by zero",
+        patterns: &[
+            "attempt to divide by zero",
+            "attempt to calculate the remainder with a divisor of zero",
+        ],
+        topic: "error_handling",
+        item: Some("divide"),
+    },
+    Rule {
+        kind: "an `unwrap` on `None`",
+        patterns: &["on a `None` value"],
+        topic: "gb_option_enum",
+        item: None,
+    },
+    Rule {
+        kind: "an `unwrap` on `Err`",
+        patterns: &["on an `Err` value"],
+        topic: "result_class",
+        item: None,
+    },
+    Rule {
+        kind: "a string sliced inside of a character",
+        patterns: &["is not a char boundary"],
+        topic: "string",
+        item: Some("exercise_3"),
+    },
+    Rule {
+        kind: "an explicit `panic!`",
+        patterns: &["explicit panic"],
+        topic: "error_handling",
+        item: Some("panic_example_01"),
+    },
+];
+
+pub fn classify(message: &str) -> Option<&'static Rule> {
+    RULES.iter().find(|rule| {
+        rule.patterns
+            .iter()
+            .any(|pattern| message.contains(pattern))
+    })
+}
+
+// note: this is an integer overflow, see the lesson `error_handling`
+//       (`production_rate_per_hour` at src/topics/error_handling.rs:47):
+//     | Panic!
+//     | ...
+pub fn explain(message: &str) -> Option<String> {
+    let rule = classify(message)?;
+    let topic = registry::topic(rule.topic)?;
+
+    let place = rule
+        .item
+        .and_then(|name| {
+            let items = lesson::items(topic.source);
+            let item = items.iter().find(|item| item.name == name)?;
+            Some(format!(
+                "`{}` at src/topics/{}.rs:{}",
+                name,
+                topic.name,
+                item.start + 1
+            ))
+        })
+        .unwrap_or_else(|| format!("src/topics/{}.rs", topic.name));
+
+    let mut text = format!(
+        "note: this is {}, see the lesson `{}`\n      ({}):",
+        rule.kind, topic.name, place
+    );
+    for line in lesson::header(topic.source) {
+        text.push_str("\n    | ");
+        text.push_str(line);
+    }
+    Some(text)
+}
+
+// Keeps the current hook, the explanation comes after its message.
+pub fn install() {
+    let previous = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        previous(info);
+        if let Some(text) = info.payload_as_str().and_then(explain) {
+            eprintln!("{}", text);
+        }
+    }));
+}
@@ -0,0 +1,133 @@
+// # Golden Output
+//
+// Many examples only print (`debug_display`, `h_flow_control`...), so their output is the lesson. Golden
+// files pin it down: the stdout of every registered example is checked in as
+// `tests/golden/<topic>/<example>.expected`, and `golden` compares a fresh run against it.
+//
+// - `golden [<selector>]`          -> compares, prints a diff for every mismatch and fails if any.
+// - `golden --bless [<selector>]`  -> writes the current output as the expected one.
+//
+// Each example runs in its own process (`exec <topic>::<example>`), which is the only way to capture what
+// it prints without touching the lesson. Examples that print a HashMap depend on its random order: their
+// lines are compared in any order, or they are not compared at all when the order is within a line.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::registry::Example;
+
+// Every line is printed on its own, but in HashMap order.
+const UNORDERED_LINES: &[&str] = &[
+    "hashmap::hashmap_exercise_1",
+    "hashmap::hashmap_excercise_4",
+];
+
+// The whole HashMap is printed on a single line.
+const NOT_COMPARED: &[&str] = &["iterators::_example_iterators_09"];
+
+pub enum Status {
+    Matches,
+    Differs { expected: String, actual: String },
+    Missing,
+    Blessed,
+    Skipped,
+}
+
+pub fn expected_path(dir: &Path, example: &Example) -> PathBuf {
+    dir.join(example.topic)
+        .join(format!("{}.expected", example.id))
+}
+
+// Runs the example in a child process of `binary` and returns what it printed on stdout.
+// Exercises that are still broken panic, what they printed before that is their output.
+pub fn capture(binary: &Path, example: &Example) -> io::Result<String> {
+    let output = Command::new(binary)
+        .args(["exec", &example.path()])
+        .output()?;
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+fn same_output(example: &Example, expected: &str, actual: &str) -> bool {
+    if UNORDERED_LINES.contains(&example.path().as_str()) {
+        let mut expected: Vec<_> = expected.lines().collect();
+        let mut actual: Vec<_> = actual.lines().collect();
+        expected.sort_unstable();
+        actual.sort_unstable();
+        expected == actual
+    } else {
+        expected == actual
+    }
+}
+
+pub fn check(
+    binary: &Path,
+    dir: &Path,
+    example: &'static Example,
+    bless: bool,
+) -> io::Result<Status> {
+    let path = expected_path(dir, example);
+    let status = if NOT_COMPARED.contains(&example.path().as_str()) {
+        Status::Skipped
+    } else {
+        let actual = capture(binary, example)?;
+        match fs::read_to_string(&path) {
+            Ok(expected) if same_output(example, &expected, &actual) => Status::Matches,
+            _ if bless => {
+                fs::create_dir_all(path.parent().unwrap())?;
+                fs::write(&path, actual)?;
+                Status::Blessed
+            }
+            Ok(expected) => Status::Differs { expected, actual },
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Status::Missing,
+            Err(e) => return Err(e),
+        }
+    };
+
+    Ok(status)
+}
+
+// `.expected` files of examples that are not registered (anymore).
+pub fn stale_files(dir: &Path, examples: &[&Example]) -> io::Result<Vec<PathBuf>> {
+    let known: Vec<PathBuf> = examples.iter().map(|e| expected_path(dir, e)).collect();
+    let mut stale = Vec::new();
+
+    if !dir.is_dir() {
+        return Ok(stale);
+    }
+    for topic in fs::read_dir(dir)? {
+        let topic = topic?.path();
+        if !topic.is_dir() {
+            continue;
+        }
+        for file in fs::read_dir(&topic)? {
+            let file = file?.path();
+            if !known.contains(&file) {
+                stale.push(file);
+            }
+        }
+    }
+
+    stale.sort();
+    Ok(stale)
+}
+
+pub fn print_diff(expected: &str, actual: &str) {
+    let expected: Vec<_> = expected.lines().collect();
+    let actual: Vec<_> = actual.lines().collect();
+
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => println!("      {}", e),
+            (e, a) => {
+                if let Some(e) = e {
+                    println!("    - {}", e);
+                }
+                if let Some(a) = a {
+                    println!("    + {}", a);
+                }
+            }
+        }
+    }
+}
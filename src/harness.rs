@@ -0,0 +1,207 @@
+// # Exercise Harness
+//
+// Exercises check themselves with `assert!`, so a wrong answer shows up as a panic. Running them directly
+// would stop at the first failure, which is not what a learner wants to see.
+//
+// The harness runs each exercise on its own thread and catches the panic instead:
+// - A panic hook records the message and the source location (`src/topics/string.rs:38:5`).
+// - The default hook is silenced while grading, so the report is the only thing printed about the failure.
+// - The previous hook is restored when the `Grader` is dropped.
+//
+// A failed assertion is told apart from any other panic by where it happened, not by its message:
+// `assert!`, `assert_eq!` and `assert_ne!` panic at the line and column they are called from, and a custom
+// message (`assert!(s.is_empty(), "not yet")`) can say anything.
+
+use std::cell::RefCell;
+use std::panic::{self, PanicHookInfo};
+use std::thread;
+
+use crate::registry::Example;
+
+#[derive(Debug, Clone)]
+pub struct Failure {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Failure {
+    fn from_hook(info: &PanicHookInfo) -> Self {
+        Self {
+            message: info.payload_as_str().unwrap_or("Box<dyn Any>").to_string(),
+            location: info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column())),
+        }
+    }
+}
+
+pub enum Outcome {
+    Passed,
+    Failed(Failure),
+}
+
+pub struct Grade {
+    pub example: &'static Example,
+    pub outcome: Outcome,
+}
+
+impl Grade {
+    pub fn passed(&self) -> bool {
+        matches!(self.outcome, Outcome::Passed)
+    }
+}
+
+thread_local! {
+    // Filled by the panic hook on the thread that panicked.
+    static LAST_FAILURE: RefCell<Option<Failure>> = const { RefCell::new(None) };
+}
+
+type PanicHook = Box<dyn Fn(&PanicHookInfo) + Sync + Send + 'static>;
+
+// Installs the recording panic hook for as long as it lives.
+pub struct Grader {
+    previous_hook: Option<PanicHook>,
+}
+
+impl Grader {
+    pub fn new() -> Self {
+        let previous_hook = panic::take_hook();
+        panic::set_hook(Box::new(|info| {
+            let failure = Failure::from_hook(info);
+            LAST_FAILURE.with(|f| *f.borrow_mut() = Some(failure));
+        }));
+
+        Self {
+            previous_hook: Some(previous_hook),
+        }
+    }
+
+    // Runs one exercise on a fresh thread and turns a panic into a `Failure`.
+    pub fn grade(&self, example: &'static Example) -> Grade {
+        let run = example.run;
+        let result = thread::Builder::new()
+            .name(example.path())
+            .spawn(move || {
+                panic::catch_unwind(run).map_err(|_| {
+                    LAST_FAILURE
+                        .with(|f| f.borrow_mut().take())
+                        .unwrap_or_else(|| Failure {
+                            message: "exercise panicked".to_string(),
+                            location: None,
+                        })
+                })
+            })
+            .expect("failed to spawn exercise thread")
+            .join();
+
+        let outcome = match result {
+            Ok(Ok(())) => Outcome::Passed,
+            Ok(Err(failure)) => Outcome::Failed(failure),
+            // Only reachable if the panic happened outside of `catch_unwind`.
+            Err(_) => Outcome::Failed(Failure {
+                message: "exercise thread panicked".to_string(),
+                location: None,
+            }),
+        };
+
+        Grade { example, outcome }
+    }
+}
+
+impl Default for Grader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Grader {
+    fn drop(&mut self) {
+        if let Some(hook) = self.previous_hook.take() {
+            panic::set_hook(hook);
+        }
+    }
+}
+
+// Whether the code at a panic's location (`src/topics/string.rs:38:5`) is an assertion. `source` reads
+// the file the location names.
+pub fn is_assertion(location: &str, source: impl FnOnce(&str) -> Option<String>) -> bool {
+    let mut parts = location.rsplitn(3, ':');
+    let (Some(column), Some(line), Some(file)) = (parts.next(), parts.next(), parts.next()) else {
+        return false;
+    };
+    let (Ok(line), Ok(column)) = (line.parse::<usize>(), column.parse::<usize>()) else {
+        return false;
+    };
+
+    // Both are 1-based, the column counts chars.
+    source(file).is_some_and(|text| {
+        let code: String = text
+            .lines()
+            .nth(line.saturating_sub(1))
+            .unwrap_or_default()
+            .chars()
+            .skip(column.saturating_sub(1))
+            .collect();
+        ["assert!", "assert_eq!", "assert_ne!", "debug_assert"]
+            .iter()
+            .any(|assertion| code.starts_with(assertion))
+    })
+}
+
+pub fn print_summary(grades: &[Grade]) {
+    print!("{}", summary(grades));
+}
+
+// The table of results, one exercise per line, and the totals.
+pub fn summary(grades: &[Grade]) -> String {
+    let topic_width = grades
+        .iter()
+        .map(|g| g.example.topic.len())
+        .chain(["Topic".len()])
+        .max()
+        .unwrap_or(0);
+    let id_width = grades
+        .iter()
+        .map(|g| g.example.id.len())
+        .chain(["Exercise".len()])
+        .max()
+        .unwrap_or(0);
+
+    let mut out = format!(
+        "{:topic_width$}  {:id_width$}  Result\n",
+        "Topic",
+        "Exercise",
+        topic_width = topic_width,
+        id_width = id_width
+    );
+    out += &"-".repeat(topic_width + id_width + 10);
+    out.push('\n');
+    for grade in grades {
+        out += &format!(
+            "{:topic_width$}  {:id_width$}  {}\n",
+            grade.example.topic,
+            grade.example.id,
+            if grade.passed() { "PASS" } else { "FAIL" },
+            topic_width = topic_width,
+            id_width = id_width
+        );
+    }
+
+    let passed = grades.iter().filter(|g| g.passed()).count();
+    out += &format!(
+        "\n{} exercises, {} passed, {} failed\n",
+        grades.len(),
+        passed,
+        grades.len() - passed
+    );
+    out
+}
+
+// The exit code of `grade`: 1 as soon as one exercise fails.
+pub fn exit_code(grades: &[Grade]) -> i32 {
+    if grades.iter().all(Grade::passed) {
+        0
+    } else {
+        1
+    }
+}
@@ -0,0 +1,302 @@
+// # Lesson Sources
+//
+// Helpers to read a topic's source the way a person reads it: the `//` prose blocks written at the start
+// of a line, and the code in between them.
+//
+// Comments inside of functions (indented) belong to the code. The registration at the bottom of each
+// module (the imports of `REGISTRATION`, then `EXAMPLES` and `QUIZ`) is not part of the lesson and is cut
+// off. rustfmt keeps those imports sorted, so `REGISTRY_MARKER` isn't always the first of them.
+
+#[derive(Debug, PartialEq)]
+pub enum Block<'a> {
+    // Comment lines with the `//` marker removed.
+    Prose(Vec<&'a str>),
+    // Raw source lines.
+    Code(Vec<&'a str>),
+}
+
+pub const REGISTRY_MARKER: &str = "use crate::registry::Example;";
+
+// The imports the registration needs, each on its own line.
+pub const REGISTRATION: &[&str] = &[
+    REGISTRY_MARKER,
+    "use crate::question;",
+    "use crate::quiz::Question;",
+];
+
+// The lesson without its registration: everything before the first line that is one of `REGISTRATION`.
+pub fn body(source: &str) -> &str {
+    let mut start = 0;
+    for line in source.split_inclusive('\n') {
+        if REGISTRATION.contains(&line.trim_end()) {
+            return source[..start].trim_end();
+        }
+        start += line.len();
+    }
+    source.trim_end()
+}
+
+// `// text` -> `text`, `/// text` -> `text`, `//` -> ``
+pub fn prose_line(line: &str) -> Option<&str> {
+    let rest = line.strip_prefix("//")?;
+    let rest = rest.strip_prefix('/').unwrap_or(rest);
+    Some(rest.strip_prefix(' ').unwrap_or(rest))
+}
+
+pub fn blocks(source: &str) -> Vec<Block<'_>> {
+    let mut blocks = Vec::new();
+    let mut current: Option<Block> = None;
+    // Blank lines are kept only if the block goes on after them.
+    let mut blanks = 0;
+
+    for line in body(source).lines() {
+        if line.trim().is_empty() {
+            blanks += 1;
+            continue;
+        }
+
+        match (prose_line(line), &mut current) {
+            (Some(text), Some(Block::Prose(lines))) => {
+                lines.extend(std::iter::repeat_n("", blanks));
+                lines.push(text);
+            }
+            (None, Some(Block::Code(lines))) => {
+                lines.extend(std::iter::repeat_n("", blanks));
+                lines.push(line);
+            }
+            (prose, _) => {
+                blocks.extend(current.take());
+                current = Some(match prose {
+                    Some(text) => Block::Prose(vec![text]),
+                    None => Block::Code(vec![line]),
+                });
+            }
+        }
+        blanks = 0;
+    }
+
+    blocks.extend(current);
+    blocks
+}
+
+// The first prose block, i.e. the explanation a topic opens with. Empty if the file starts with code.
+pub fn header(source: &str) -> Vec<&str> {
+    match blocks(source).into_iter().next() {
+        Some(Block::Prose(lines)) => lines,
+        _ => Vec::new(),
+    }
+}
+
+// ## Items
+//
+// A light scan of the items a lesson defines (functions, types, impl blocks), good enough to name the
+// function a line belongs to or to print one function with its comment. It counts braces outside of
+// strings and comments; it's not a parser.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ItemKind {
+    Function,
+    // struct, enum, trait, type alias or union
+    Type,
+    Impl,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Item<'a> {
+    pub kind: ItemKind,
+    // `_Fibonacci`, `next`, or `Iterator for _Fibonacci` for impl blocks.
+    pub name: &'a str,
+    // 0-based line numbers in `body(source)`, `end` included.
+    pub start: usize,
+    pub end: usize,
+    // First line of the comment block right above the item (no blank line in between),
+    // `start` when there is none.
+    pub doc_start: usize,
+}
+
+impl Item<'_> {
+    pub fn contains(&self, line: usize) -> bool {
+        self.doc_start <= line && line <= self.end
+    }
+}
+
+pub fn items(source: &str) -> Vec<Item<'_>> {
+    let lines: Vec<&str> = body(source).lines().collect();
+    let mut items: Vec<Item> = Vec::new();
+    // Items whose body is not closed yet: (index in `items`, depth before the item, body opened?)
+    let mut open: Vec<(usize, usize, bool)> = Vec::new();
+    let mut depth = 0;
+    let mut in_block_comment = false;
+
+    for (number, line) in lines.iter().enumerate() {
+        if let Some((kind, name)) = item_header(line) {
+            let mut doc_start = number;
+            while doc_start > 0 && lines[doc_start - 1].trim_start().starts_with("//") {
+                doc_start -= 1;
+            }
+            items.push(Item {
+                kind,
+                name,
+                start: number,
+                end: number,
+                doc_start,
+            });
+            open.push((items.len() - 1, depth, false));
+        }
+
+        let (new_depth, deepest) = count_braces(line, depth, &mut in_block_comment);
+        depth = new_depth;
+        let code = line.split("//").next().unwrap_or(line).trim_end();
+
+        while let Some(&(index, item_depth, opened)) = open.last() {
+            // `fn _take<T>(_v: &T) {}` opens and closes its body on the same line.
+            let opened = opened || deepest > item_depth;
+            let closed = if opened {
+                depth <= item_depth
+            } else {
+                // `struct Unit;`, `fn next(&mut self) -> Option<Self::Item>;`
+                code.ends_with(';')
+            };
+
+            if closed {
+                items[index].end = number;
+                open.pop();
+            } else {
+                open.last_mut().unwrap().2 = opened;
+                break;
+            }
+        }
+    }
+
+    for (index, _, _) in open {
+        items[index].end = lines.len().saturating_sub(1);
+    }
+    items
+}
+
+// The innermost item a line belongs to, its comment included.
+pub fn item_at<'i, 'a>(items: &'i [Item<'a>], line: usize) -> Option<&'i Item<'a>> {
+    items
+        .iter()
+        .filter(|item| item.contains(line))
+        .min_by_key(|item| item.end - item.doc_start)
+}
+
+fn item_header(line: &str) -> Option<(ItemKind, &str)> {
+    let mut rest = line.trim_start();
+    if rest.starts_with("//") {
+        return None;
+    }
+    for prefix in ["pub(crate) ", "pub(super) ", "pub "] {
+        if let Some(r) = rest.strip_prefix(prefix) {
+            rest = r;
+            break;
+        }
+    }
+    for qualifier in ["const ", "async ", "unsafe ", "extern \"C\" "] {
+        if let Some(r) = rest.strip_prefix(qualifier) {
+            rest = r;
+        }
+    }
+
+    if let Some(r) = rest.strip_prefix("impl") {
+        // `impl<T: Display> Summary for Point<T> {` -> `Summary for Point<T>`
+        let r = if r.starts_with('<') {
+            skip_generics(r)
+        } else {
+            r.strip_prefix(' ')?
+        };
+        let name = r.split(['{', '\n']).next().unwrap_or(r).trim();
+        let name = name.split(" where").next().unwrap_or(name).trim();
+        return Some((ItemKind::Impl, name));
+    }
+
+    let (kind, r) = [
+        ("fn ", ItemKind::Function),
+        ("struct ", ItemKind::Type),
+        ("enum ", ItemKind::Type),
+        ("trait ", ItemKind::Type),
+        ("type ", ItemKind::Type),
+        ("union ", ItemKind::Type),
+    ]
+    .into_iter()
+    .find_map(|(keyword, kind)| rest.strip_prefix(keyword).map(|r| (kind, r)))?;
+
+    let end = r
+        .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .unwrap_or(r.len());
+    if end == 0 {
+        return None;
+    }
+    Some((kind, &r[..end]))
+}
+
+fn skip_generics(s: &str) -> &str {
+    let mut depth = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '<' => depth += 1,
+            '>' => {
+                depth -= 1;
+                if depth == 0 {
+                    return s[i + 1..].trim_start();
+                }
+            }
+            _ => {}
+        }
+    }
+    ""
+}
+
+// Returns the depth at the end of the line and the deepest it went.
+fn count_braces(line: &str, mut depth: usize, in_block_comment: &mut bool) -> (usize, usize) {
+    let mut deepest = depth;
+    let mut chars = line.chars().peekable();
+    let mut in_string = false;
+
+    while let Some(c) = chars.next() {
+        if *in_block_comment {
+            if c == '*' && chars.peek() == Some(&'/') {
+                chars.next();
+                *in_block_comment = false;
+            }
+            continue;
+        }
+        if in_string {
+            match c {
+                '\\' => {
+                    chars.next();
+                }
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+
+        match c {
+            '"' => in_string = true,
+            // A char literal such as '{' or '"'.
+            '\'' => {
+                let rest: String = chars.clone().take(3).collect();
+                if rest.starts_with('\\') {
+                    chars.nth(2);
+                } else if rest.chars().nth(1) == Some('\'') {
+                    chars.nth(1);
+                }
+            }
+            '/' if chars.peek() == Some(&'/') => break,
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                *in_block_comment = true;
+            }
+            '{' => {
+                depth += 1;
+                deepest = deepest.max(depth);
+            }
+            '}' => depth = depth.saturating_sub(1),
+            _ => {}
+        }
+    }
+
+    (depth, deepest)
+}
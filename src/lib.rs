@@ -0,0 +1,66 @@
+// # rust-trainning
+//
+// The lessons live in `topics`, one module per subject. Each module keeps its explanations as comments
+// next to the code and registers its runnable examples in the `registry`.
+//
+// The crate is split in two:
+// - This library, which can be used from integration tests, benches and other crates.
+// - A thin binary (`src/main.rs`) that only hands the command line to the `runner`.
+//
+// The lessons are public, each module as it is written (`topics::generics::Point2`,
+// `topics::closures::_factory`...), and so is everything that reads them: the `registry`, the lesson
+// sources (`lesson`), `search`, the `book`, the `quiz`... Only the two modules that write the `runner`'s
+// output files, `golden` and `report`, stay inside the crate.
+
+pub mod alloc_stats;
+pub mod book;
+pub mod checker;
+pub mod collections;
+pub mod curriculum;
+pub mod explain;
+pub(crate) mod golden;
+pub mod harness;
+pub mod json;
+pub mod lesson;
+pub mod progress;
+pub mod quiz;
+pub mod registry;
+pub(crate) mod report;
+pub mod runner;
+pub mod search;
+pub mod shell;
+pub mod text;
+pub mod watch;
+
+// Lessons keep unused items, commented-out errors and exercises that are wrong on purpose, and show
+// some code the long way before the idiomatic one: these lints are relaxed for them only.
+#[allow(
+    dead_code,
+    unused_imports,
+    unused_must_use,
+    unused_mut,
+    unused_variables,
+    non_upper_case_globals,
+    mismatched_lifetime_syntaxes,
+    clippy::bind_instead_of_map,
+    clippy::clone_on_copy,
+    clippy::empty_line_after_doc_comments,
+    clippy::extra_unused_lifetimes,
+    clippy::if_same_then_else,
+    clippy::manual_map,
+    clippy::manual_range_patterns,
+    clippy::match_single_binding,
+    clippy::needless_borrows_for_generic_args,
+    clippy::needless_lifetimes,
+    clippy::needless_range_loop,
+    clippy::needless_return,
+    clippy::never_loop,
+    clippy::new_without_default,
+    clippy::print_literal,
+    clippy::ptr_arg,
+    clippy::question_mark,
+    clippy::size_of_ref,
+    clippy::unnecessary_cast,
+    clippy::useless_vec
+)]
+pub mod topics;
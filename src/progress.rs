@@ -0,0 +1,307 @@
+// # Learner Progress
+//
+// Remembers which exercises each learner has passed, across runs, in a small JSON file:
+//
+// {
+//   "learners": {
+//     "alice": {
+//       "passed": ["hashmap::hashmap_exercise_3", "string::exercise_3"],
+//       "hints": { "string::exercise_1": 2 }
+//     }
+//   }
+// }
+//
+// Where the file lives:
+// - `$RUST_TRAINNING_PROGRESS`, if set.
+// - Otherwise `$XDG_DATA_HOME/rust-trainning/progress.json` (`~/.local/share/...` when XDG_DATA_HOME is unset).
+//
+// Who the learner is: `$RUST_TRAINNING_LEARNER`, otherwise `$USER`.
+//
+// `hints` counts the hints each learner has revealed per exercise, so an instructor can see who needed
+// help, and where, with `progress --all`.
+//
+// Exercises are suggested in the order of the `curriculum`: a topic comes after its prerequisites.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::curriculum;
+use crate::json::{self, Value};
+use crate::registry::{self, Example, Topic};
+
+#[derive(Debug, Default, Clone)]
+pub struct Learner {
+    pub passed: BTreeSet<String>,
+    pub hints: BTreeMap<String, usize>,
+}
+
+impl Learner {
+    pub fn has_passed(&self, example: &Example) -> bool {
+        self.passed.contains(&example.path())
+    }
+
+    // Only the latest result counts: an exercise that passed once and is broken again is unsolved.
+    pub fn record(&mut self, example: &Example, passed: bool) {
+        if passed {
+            self.passed.insert(example.path());
+        } else {
+            self.passed.remove(&example.path());
+        }
+    }
+
+    pub fn hints_revealed(&self, example: &Example) -> usize {
+        self.hints.get(&example.path()).copied().unwrap_or(0)
+    }
+
+    // Reveals one more hint, if there is one left, and returns how many are revealed.
+    pub fn reveal_hint(&mut self, example: &Example) -> usize {
+        let revealed = (self.hints_revealed(example) + 1).min(example.hints.len());
+        if revealed > 0 {
+            self.hints.insert(example.path(), revealed);
+        }
+        revealed
+    }
+
+    pub fn hints_used(&self) -> usize {
+        self.hints.values().sum()
+    }
+
+    fn from_json(value: &Value) -> Self {
+        let passed = value
+            .get("passed")
+            .and_then(Value::as_array)
+            .unwrap_or_default()
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect();
+        let hints = value
+            .get("hints")
+            .and_then(Value::as_object)
+            .into_iter()
+            .flatten()
+            .filter_map(|(path, count)| Some((path.clone(), count.as_f64()? as usize)))
+            .collect();
+
+        Self { passed, hints }
+    }
+
+    fn to_json(&self) -> Value {
+        let passed: Vec<&str> = self.passed.iter().map(String::as_str).collect();
+        let hints = self
+            .hints
+            .iter()
+            .map(|(path, &count)| (path.clone(), (count as f64).into()))
+            .collect();
+        Value::Object(BTreeMap::from([
+            ("passed".to_string(), passed.into()),
+            ("hints".to_string(), Value::Object(hints)),
+        ]))
+    }
+}
+
+pub struct Progress {
+    path: PathBuf,
+    learners: BTreeMap<String, Learner>,
+}
+
+impl Progress {
+    pub fn default_path() -> PathBuf {
+        if let Some(path) = env::var_os("RUST_TRAINNING_PROGRESS") {
+            return PathBuf::from(path);
+        }
+
+        let data_home = env::var_os("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".local/share")))
+            .unwrap_or_else(|| PathBuf::from("."));
+
+        data_home.join("rust-trainning").join("progress.json")
+    }
+
+    // A missing file is not an error, it just means nobody has passed anything yet.
+    pub fn load(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+
+        let mut learners = BTreeMap::new();
+        if !contents.trim().is_empty() {
+            let value = json::parse(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            if let Some(map) = value.get("learners").and_then(Value::as_object) {
+                for (name, learner) in map {
+                    learners.insert(name.clone(), Learner::from_json(learner));
+                }
+            }
+        }
+
+        Ok(Self { path, learners })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let learners = self
+            .learners
+            .iter()
+            .map(|(name, learner)| (name.clone(), learner.to_json()))
+            .collect();
+        let root = Value::Object(BTreeMap::from([(
+            "learners".to_string(),
+            Value::Object(learners),
+        )]));
+
+        fs::write(&self.path, root.pretty() + "\n")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn learner(&self, name: &str) -> Option<&Learner> {
+        self.learners.get(name)
+    }
+
+    pub fn learners(&self) -> impl Iterator<Item = (&str, &Learner)> {
+        self.learners
+            .iter()
+            .map(|(name, learner)| (name.as_str(), learner))
+    }
+
+    pub fn learner_mut(&mut self, name: &str) -> &mut Learner {
+        self.learners.entry(name.to_string()).or_default()
+    }
+}
+
+pub fn current_learner() -> String {
+    env::var("RUST_TRAINNING_LEARNER")
+        .or_else(|_| env::var("USER"))
+        .unwrap_or_else(|_| "learner".to_string())
+}
+
+// Topics that have at least one exercise, in curriculum order. A broken prerequisite graph is caught by
+// the tests, the module names are good enough until it's fixed.
+pub fn curriculum() -> Vec<&'static Topic> {
+    let topics = curriculum::curriculum().unwrap_or_else(|_| {
+        let mut topics: Vec<_> = registry::topics().iter().collect();
+        topics.sort_by_key(|topic| topic.name);
+        topics
+    });
+
+    topics
+        .into_iter()
+        .filter(|topic| topic.exercises().next().is_some())
+        .collect()
+}
+
+pub fn next_unsolved(learner: &Learner) -> Option<&'static Example> {
+    curriculum()
+        .into_iter()
+        .flat_map(|topic| topic.exercises())
+        .find(|example| !learner.has_passed(example))
+}
+
+pub fn print_report(name: &str, progress: &Progress) {
+    // Nothing to do if stdout is closed.
+    let _ = write_report(&mut io::stdout().lock(), name, progress);
+}
+
+pub fn write_report(out: &mut impl Write, name: &str, progress: &Progress) -> io::Result<()> {
+    let empty = Learner::default();
+    let learner = progress.learner(name).unwrap_or(&empty);
+    let topics = curriculum();
+
+    writeln!(
+        out,
+        "Progress of {} ({})\n",
+        name,
+        progress.path().display()
+    )?;
+
+    let width = topics.iter().map(|t| t.title.len()).max().unwrap_or(0);
+    let (mut total, mut passed) = (0, 0);
+    for topic in topics {
+        let exercises: Vec<_> = topic.exercises().collect();
+        let done = exercises.iter().filter(|e| learner.has_passed(e)).count();
+        total += exercises.len();
+        passed += done;
+
+        let bar: String = (0..exercises.len())
+            .map(|i| if i < done { '#' } else { '.' })
+            .collect();
+        writeln!(
+            out,
+            "{:width$}  {:>2}/{:<2} [{}]",
+            topic.title,
+            done,
+            exercises.len(),
+            bar,
+            width = width
+        )?;
+    }
+
+    writeln!(out, "\nOverall: {}/{} exercises passed", passed, total)?;
+    if learner.hints_used() > 0 {
+        writeln!(out, "Hints revealed: {}", hints_summary(learner))?;
+    }
+    match next_unsolved(learner) {
+        Some(example) => writeln!(out, "Next: {} ({})", example.path(), example.title),
+        None => writeln!(out, "All exercises passed, well done!"),
+    }
+}
+
+// `string::exercise_1 2/3, vectors::vectors_exercise_4 1/2`
+fn hints_summary(learner: &Learner) -> String {
+    learner
+        .hints
+        .iter()
+        .map(|(path, count)| {
+            let available = registry::select(path).map_or(0, |examples| examples[0].hints.len());
+            format!("{} {}/{}", path, count, available)
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// For the instructor: every learner in the file, how far they got and which hints they needed.
+pub fn print_class_report(progress: &Progress) {
+    let total: usize = curriculum().iter().map(|t| t.exercises().count()).sum();
+
+    println!("Learners in {}\n", progress.path().display());
+    if progress.learners().next().is_none() {
+        println!("Nobody has graded an exercise or asked for a hint yet.");
+        return;
+    }
+
+    let width = progress
+        .learners()
+        .map(|(name, _)| name.len())
+        .max()
+        .unwrap_or(0);
+    for (name, learner) in progress.learners() {
+        let passed = registry::examples()
+            .filter(|e| e.exercise && learner.has_passed(e))
+            .count();
+        let hints = match learner.hints_used() {
+            0 => "no hints".to_string(),
+            _ => format!("hints: {}", hints_summary(learner)),
+        };
+        println!(
+            "{:width$}  {:>2}/{:<2}  {}",
+            name,
+            passed,
+            total,
+            hints,
+            width = width
+        );
+    }
+}
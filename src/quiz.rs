@@ -0,0 +1,205 @@
+// # Quiz
+//
+// "What does this evaluate to?" questions built from the lessons' own assertions, for example
+// `&s[7..10]` on `"hello, ぁまピ"` from `string`, or `1000 as u8` from `type_coersion`.
+//
+// Topics declare their questions in a `pub const QUIZ` slice, next to `EXAMPLES`, with the `question!`
+// macro. The macro keeps the snippet's source (to show it) and compiles the same snippet into a function,
+// so the expected answer is never typed by hand: it's whatever running the snippet gives, in `{:?}` form.
+//
+// The snippets are the lessons' own code: each line of one must still be in its topic's lesson (the tests
+// check it), so that a question can't drift away from what the lesson teaches. A snippet that panics
+// has no answer, its question is skipped.
+//
+// Answers are compared without whitespace, and quotes are optional: `"ぁ"`, `ぁ`, `[2,3]` and `[2, 3]` are
+// all fine.
+
+use std::io::{self, BufRead, Write};
+use std::panic;
+
+use crate::registry;
+
+pub struct Question {
+    pub topic: &'static str,
+    // Statements run before the expression, as written in the source.
+    pub setup: &'static str,
+    pub expr: &'static str,
+    pub answer: fn() -> String,
+}
+
+// question!("string", { let s = String::from("hello"); } => &s[0..1])
+#[macro_export]
+macro_rules! question {
+    ($topic:literal, { $($setup:tt)* } => $expr:expr) => {
+        $crate::quiz::Question {
+            topic: $topic,
+            setup: stringify!($($setup)*),
+            expr: stringify!($expr),
+            answer: || {
+                $($setup)*
+                format!("{:?}", $expr)
+            },
+        }
+    };
+}
+
+impl Question {
+    // The panic message, if the snippet panics.
+    pub fn expected(&self) -> Result<String, String> {
+        panic::catch_unwind(self.answer).map_err(|payload| {
+            payload
+                .downcast_ref::<&str>()
+                .map(|s| s.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or_else(|| "Box<dyn Any>".to_string())
+        })
+    }
+
+    // The snippet, one statement per line (`stringify!` puts them all on one).
+    pub fn code(&self) -> Vec<&'static str> {
+        let mut lines = Vec::new();
+        let (mut depth, mut start) = (0, 0);
+        for (i, c) in self.setup.char_indices() {
+            match c {
+                '{' | '(' | '[' => depth += 1,
+                '}' | ')' | ']' => depth -= 1,
+                ';' if depth == 0 => {
+                    lines.push(self.setup[start..=i].trim());
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        // A block (`loop { .. }`) needs no `;` after it.
+        let rest = self.setup[start..].trim();
+        if !rest.is_empty() {
+            lines.push(rest);
+        }
+        lines.push(self.expr);
+        lines
+    }
+}
+
+pub fn answers_match(expected: &str, given: &str) -> bool {
+    let expected = without_whitespace(expected);
+    if expected == without_whitespace(given) {
+        return true;
+    }
+
+    // Typed without its quotes, the answer is compared as is.
+    let unquoted = expected
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| {
+            expected
+                .strip_prefix('\'')
+                .and_then(|s| s.strip_suffix('\''))
+        });
+    unquoted == Some(given.trim())
+}
+
+// Whitespace inside of string and char literals is kept, `"hello, world"` stays as is.
+fn without_whitespace(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut quote = None;
+    let mut escaped = false;
+
+    for c in s.trim().chars() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c.is_whitespace() => continue,
+            None => {}
+        }
+        out.push(c);
+    }
+
+    out
+}
+
+// All the questions, or those of one topic.
+pub fn questions(topic: Option<&str>) -> Result<Vec<&'static Question>, String> {
+    match topic {
+        Some(name) => {
+            let topic = registry::topic(name).ok_or_else(|| format!("unknown topic `{}`", name))?;
+            if topic.quiz.is_empty() {
+                return Err(format!("topic `{}` has no quiz", name));
+            }
+            Ok(topic.quiz.iter().collect())
+        }
+        None => Ok(registry::topics()
+            .iter()
+            .flat_map(|topic| topic.quiz.iter())
+            .collect()),
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Score {
+    pub asked: usize,
+    pub correct: usize,
+}
+
+// Asks every question in turn. An empty line skips a question, `quit` or the end of the input stops.
+pub fn ask<R, W>(questions: &[&Question], input: &mut R, output: &mut W) -> io::Result<Score>
+where
+    R: BufRead,
+    W: Write,
+{
+    let mut score = Score::default();
+
+    for (number, question) in questions.iter().enumerate() {
+        let expected = match question.expected() {
+            Ok(expected) => expected,
+            Err(message) => {
+                writeln!(
+                    output,
+                    "Question {}/{} ({}) is skipped, its snippet panicked: {}\n",
+                    number + 1,
+                    questions.len(),
+                    question.topic,
+                    message
+                )?;
+                continue;
+            }
+        };
+
+        writeln!(
+            output,
+            "Question {}/{} ({})\n",
+            number + 1,
+            questions.len(),
+            question.topic
+        )?;
+        for line in question.code() {
+            writeln!(output, "    {}", line)?;
+        }
+        write!(output, "\nWhat does this evaluate to? > ")?;
+        output.flush()?;
+
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 || answer.trim() == "quit" {
+            writeln!(output)?;
+            break;
+        }
+
+        score.asked += 1;
+        if answers_match(&expected, &answer) {
+            score.correct += 1;
+            writeln!(output, "Correct!\n")?;
+        } else {
+            writeln!(output, "Not quite, it evaluates to {}\n", expected)?;
+        }
+    }
+
+    writeln!(output, "Score: {}/{}", score.correct, score.asked)?;
+    Ok(score)
+}
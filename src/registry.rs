@@ -0,0 +1,147 @@
+// # Lesson Registry
+//
+// Every module in `topics` registers the examples it wants to expose in a `pub const EXAMPLES` slice.
+// An example is identified by its topic (the module name) and its id (usually the function name),
+// so it can be selected from the command line as `topic::id` without editing `main.rs`.
+//
+// The registry is just data: a list of `Topic`s, each one pointing to its `Example`s. Running an example
+// means calling the plain `fn()` stored in it.
+//
+// Some examples are exercises: they check themselves with `assert!` and print "Success!" at the end.
+// Those are registered with `Example::exercise` so the `harness` can grade them, and can carry hints
+// (`.hints(&[...])`), from a nudge to something close to the answer, revealed one at a time by `hint`.
+
+use crate::quiz::Question;
+
+#[derive(Clone, Copy)]
+pub struct Example {
+    pub topic: &'static str,
+    pub id: &'static str,
+    pub title: &'static str,
+    pub run: fn(),
+    pub exercise: bool,
+    pub hints: &'static [&'static str],
+}
+
+impl Example {
+    pub const fn new(
+        topic: &'static str,
+        id: &'static str,
+        title: &'static str,
+        run: fn(),
+    ) -> Self {
+        Self {
+            topic,
+            id,
+            title,
+            run,
+            exercise: false,
+            hints: &[],
+        }
+    }
+
+    pub const fn exercise(
+        topic: &'static str,
+        id: &'static str,
+        title: &'static str,
+        run: fn(),
+    ) -> Self {
+        Self {
+            exercise: true,
+            ..Self::new(topic, id, title, run)
+        }
+    }
+
+    pub const fn hints(self, hints: &'static [&'static str]) -> Self {
+        Self { hints, ..self }
+    }
+
+    /// Full path used on the command line, e.g. `closures::_example_closures_04`.
+    pub fn path(&self) -> String {
+        format!("{}::{}", self.topic, self.id)
+    }
+}
+
+// Topics also carry their own source code (`include_str!`), so the lessons' comments can be shown,
+// searched and turned into a book without access to the repository. Prerequisites and quiz questions
+// are optional and added with `requires` and `quiz`.
+pub struct Topic {
+    pub name: &'static str,
+    pub title: &'static str,
+    pub examples: &'static [Example],
+    pub source: &'static str,
+    // Names of the topics to learn first.
+    pub requires: &'static [&'static str],
+    pub quiz: &'static [Question],
+}
+
+impl Topic {
+    pub const fn new(
+        name: &'static str,
+        title: &'static str,
+        examples: &'static [Example],
+        source: &'static str,
+    ) -> Self {
+        Self {
+            name,
+            title,
+            examples,
+            source,
+            requires: &[],
+            quiz: &[],
+        }
+    }
+
+    pub const fn requires(self, topics: &'static [&'static str]) -> Self {
+        Self {
+            requires: topics,
+            ..self
+        }
+    }
+
+    pub const fn quiz(self, questions: &'static [Question]) -> Self {
+        Self {
+            quiz: questions,
+            ..self
+        }
+    }
+
+    pub fn exercises(&self) -> impl Iterator<Item = &'static Example> {
+        self.examples.iter().filter(|example| example.exercise)
+    }
+
+    pub fn example(&self, id: &str) -> Option<&'static Example> {
+        self.examples.iter().find(|example| example.id == id)
+    }
+}
+
+pub fn topics() -> &'static [Topic] {
+    crate::topics::TOPICS
+}
+
+pub fn topic(name: &str) -> Option<&'static Topic> {
+    topics().iter().find(|topic| topic.name == name)
+}
+
+pub fn examples() -> impl Iterator<Item = &'static Example> {
+    topics().iter().flat_map(|topic| topic.examples.iter())
+}
+
+// A selector is what the user types after `run`: either a whole topic (`closures`)
+// or a single example inside of it (`closures::_example_closures_04`).
+pub fn select(selector: &str) -> Result<Vec<&'static Example>, String> {
+    match selector.split_once("::") {
+        Some((topic_name, id)) => {
+            let topic =
+                topic(topic_name).ok_or_else(|| format!("unknown topic `{}`", topic_name))?;
+            let example = topic
+                .example(id)
+                .ok_or_else(|| format!("unknown example `{}` in topic `{}`", id, topic_name))?;
+            Ok(vec![example])
+        }
+        None => {
+            let topic = topic(selector).ok_or_else(|| format!("unknown topic `{}`", selector))?;
+            Ok(topic.examples.iter().collect())
+        }
+    }
+}
@@ -0,0 +1,284 @@
+// # Reports
+//
+// `report` runs examples and writes the results in a format other tools can ingest, instead of the
+// "Success!" lines and tables meant for people:
+//
+// - `jsonl` (JSON Lines): one object per example.
+//   {"duration_ms":0.8,"example":"exercise_3","exercise":true,"learner":"alice","message":null,
+//    "status":"pass","stdout":"Success!\n","title":"...","topic":"string"}
+// - `junit`: JUnit XML, one `<testsuite>` per topic, as understood by CI servers and dashboards.
+//
+// Like `golden`, every example runs in its own process (`exec`) so its stdout can be captured. The status is:
+// - `pass`  -> the example returned.
+// - `fail`  -> an exercise failed one of its assertions, i.e. the answer is wrong. The panic happened on an
+//              `assert*!` line, see `harness::is_assertion`.
+// - `panic` -> anything else panicked (`unwrap` on `None`, index out of bounds, explicit `panic!`...).
+//
+// The duration is the wall time of the example's process.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, Instant};
+
+use crate::harness;
+use crate::json::Value;
+use crate::registry::{self, Example};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    Panic,
+}
+
+impl Status {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Status::Pass => "pass",
+            Status::Fail => "fail",
+            Status::Panic => "panic",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    JsonLines,
+    Junit,
+}
+
+impl Format {
+    pub fn parse(name: &str) -> Result<Self, String> {
+        match name {
+            "jsonl" => Ok(Format::JsonLines),
+            "junit" => Ok(Format::Junit),
+            other => Err(format!("unknown report format `{}` (jsonl, junit)", other)),
+        }
+    }
+}
+
+pub struct Record {
+    pub example: &'static Example,
+    pub status: Status,
+    pub duration: Duration,
+    pub stdout: String,
+    // The panic message and where it happened, for `fail` and `panic`.
+    pub message: Option<String>,
+}
+
+pub fn run(binary: &Path, example: &'static Example) -> io::Result<Record> {
+    let start = Instant::now();
+    let output = Command::new(binary)
+        .args(["exec", &example.path()])
+        .env("RUST_BACKTRACE", "0")
+        .output()?;
+    let duration = start.elapsed();
+
+    let (status, message) = if output.status.success() {
+        (Status::Pass, None)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let failed_assertion =
+            panic_location(&stderr).is_some_and(|at| harness::is_assertion(at, lesson_source));
+        let status = if example.exercise && failed_assertion {
+            Status::Fail
+        } else {
+            Status::Panic
+        };
+        (status, Some(panic_message(&stderr)))
+    };
+
+    Ok(Record {
+        example,
+        status,
+        duration,
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        message,
+    })
+}
+
+// thread 'main' (1234) panicked at src/topics/string.rs:38:5:
+// assertion `left == right` failed
+//   left: "hello, world!"
+//  right: "hello, wordl!"
+// note: run with `RUST_BACKTRACE=1` environment variable to display a backtrace
+//
+// -> "assertion `left == right` failed\n  left: ...\n right: ...\nat src/topics/string.rs:38:5"
+pub fn panic_message(stderr: &str) -> String {
+    let mut lines = stderr
+        .lines()
+        .skip_while(|line| !line.contains(" panicked at "));
+    let (Some(_), Some(location)) = (lines.next(), panic_location(stderr)) else {
+        return "the process exited with an error".to_string();
+    };
+
+    let mut message: Vec<&str> = lines
+        .take_while(|line| !line.starts_with("note:") && !line.starts_with("stack backtrace:"))
+        .collect();
+    let at = format!("at {}", location);
+    message.push(&at);
+    message.join("\n")
+}
+
+// `src/topics/string.rs:38:5`
+pub fn panic_location(stderr: &str) -> Option<&str> {
+    let header = stderr.lines().find(|line| line.contains(" panicked at "))?;
+    let location = header.split(" panicked at ").nth(1)?;
+    Some(location.trim_end_matches(':'))
+}
+
+// The lessons are in the binary, other files are read from the crate's directory.
+fn lesson_source(file: &str) -> Option<String> {
+    registry::topics()
+        .iter()
+        .find(|topic| file == format!("src/topics/{}.rs", topic.name))
+        .map(|topic| topic.source.to_string())
+        .or_else(|| fs::read_to_string(file).ok())
+}
+
+pub fn json_line(record: &Record, learner: &str) -> String {
+    let example = record.example;
+    let fields = [
+        ("learner", learner.into()),
+        ("topic", example.topic.into()),
+        ("example", example.id.into()),
+        ("title", example.title.into()),
+        ("exercise", example.exercise.into()),
+        ("status", record.status.as_str().into()),
+        (
+            "duration_ms",
+            (record.duration.as_secs_f64() * 1000.0).into(),
+        ),
+        ("stdout", record.stdout.as_str().into()),
+        (
+            "message",
+            record.message.as_deref().map_or(Value::Null, Value::from),
+        ),
+    ];
+
+    Value::Object(
+        fields
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), value))
+            .collect(),
+    )
+    .to_string()
+}
+
+pub fn json_lines(records: &[Record], learner: &str) -> String {
+    records
+        .iter()
+        .map(|record| json_line(record, learner) + "\n")
+        .collect()
+}
+
+pub fn junit(records: &[Record], learner: &str) -> String {
+    let count = |records: &[&Record], status| records.iter().filter(|r| r.status == status).count();
+    let seconds =
+        |records: &[&Record]| -> f64 { records.iter().map(|r| r.duration.as_secs_f64()).sum() };
+
+    // Topics in the order their examples ran.
+    let mut topics: Vec<(&str, Vec<&Record>)> = Vec::new();
+    for record in records {
+        match topics
+            .iter_mut()
+            .find(|(topic, _)| *topic == record.example.topic)
+        {
+            Some((_, records)) => records.push(record),
+            None => topics.push((record.example.topic, vec![record])),
+        }
+    }
+
+    let all: Vec<&Record> = records.iter().collect();
+    let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(
+        out,
+        "<testsuites name=\"rust-trainning\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.3}\">",
+        all.len(),
+        count(&all, Status::Fail),
+        count(&all, Status::Panic),
+        seconds(&all)
+    );
+
+    for (topic, records) in &topics {
+        let _ = writeln!(
+            out,
+            "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" time=\"{:.3}\">",
+            escape(topic),
+            records.len(),
+            count(records, Status::Fail),
+            count(records, Status::Panic),
+            seconds(records)
+        );
+        let _ = writeln!(
+            out,
+            "    <properties>\n      <property name=\"learner\" value=\"{}\"/>\n    </properties>",
+            escape(learner)
+        );
+
+        for record in records {
+            let _ = writeln!(
+                out,
+                "    <testcase name=\"{}\" classname=\"{}\" time=\"{:.3}\">",
+                escape(record.example.id),
+                escape(topic),
+                record.duration.as_secs_f64()
+            );
+
+            let message = record.message.as_deref().unwrap_or_default();
+            let summary = message.lines().next().unwrap_or_default();
+            match record.status {
+                Status::Pass => {}
+                Status::Fail => {
+                    let _ = writeln!(
+                        out,
+                        "      <failure message=\"{}\">{}</failure>",
+                        escape(summary),
+                        escape(message)
+                    );
+                }
+                Status::Panic => {
+                    let _ = writeln!(
+                        out,
+                        "      <error message=\"{}\" type=\"panic\">{}</error>",
+                        escape(summary),
+                        escape(message)
+                    );
+                }
+            }
+            if !record.stdout.is_empty() {
+                let _ = writeln!(
+                    out,
+                    "      <system-out>{}</system-out>",
+                    escape(&record.stdout)
+                );
+            }
+            out.push_str("    </testcase>\n");
+        }
+        out.push_str("  </testsuite>\n");
+    }
+
+    out.push_str("</testsuites>\n");
+    out
+}
+
+// XML 1.0 can't contain most control characters, even escaped, so they are dropped.
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            '\t' | '\n' | '\r' => out.push(c),
+            c if c.is_control() => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,666 @@
+// # Runner
+//
+// Command line front-end for the lesson registry.
+//
+// Usage:
+// - `list`                    -> prints every topic and its registered examples.
+// - `run <topic>`             -> runs all the examples of a topic, in registration order.
+// - `run <topic>::<example>`  -> runs a single example.
+// - `run --stats <selector>`  -> also reports the wall time of every example, and its allocations when built
+//                                with the `alloc-stats` feature.
+// - `grade [<selector>]`      -> runs the exercises in isolation, prints a pass/fail table and records
+//                                the results in the learner's progress file.
+// - `progress [--all]`         -> shows the completion per topic and the next unsolved exercise, or a line
+//                                per learner with the hints they revealed (for instructors).
+// - `hint <topic>::<exercise>` -> reveals the next hint of an exercise, and records it in the progress file.
+// - `check <topic>::<exercise> <file> [--timeout <secs>] [--memory <MB>]`
+//                             -> compiles a solution written by the learner and runs the exercise against it,
+//                                in a sandbox.
+// - `watch`                   -> re-runs the topic of every lesson file that gets saved.
+// - `shell`                   -> an interactive shell to browse, show and run the lessons' functions.
+// - `curriculum [--dot]`      -> prints the topics in the order of their prerequisites, or the graph in DOT.
+// - `quiz [<topic>]`          -> asks "what does this evaluate to?" questions taken from the lessons.
+// - `search <term>`           -> finds the lessons that mention a term, in their comments, names or code.
+// - `golden [--bless] [<sel>]` -> compares the output of the examples with `tests/golden`, or updates it.
+// - `report [--format jsonl|junit] [--output <file>] [<sel>]`
+//                             -> runs the examples and writes machine-readable results (stdout by default).
+// - `book [<dir>]`            -> writes the lessons as a Markdown book (`book/` by default).
+//
+// A panic in `run` or in the `shell` is followed by a pointer to the lesson that explains it (`explain`).
+
+use std::env;
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::alloc_stats;
+use crate::book;
+use crate::checker::{self, Verdict};
+use crate::curriculum;
+use crate::explain;
+use crate::golden::{self, Status};
+use crate::harness::{self, Grade, Grader, Outcome};
+use crate::progress::{self, Progress};
+use crate::quiz;
+use crate::registry::{self, Example};
+use crate::report;
+use crate::search;
+use crate::shell;
+use crate::watch;
+
+pub enum Command {
+    List,
+    Run {
+        selector: String,
+        stats: bool,
+    },
+    Grade(Option<String>),
+    Progress {
+        all: bool,
+    },
+    Hint(String),
+    Check {
+        exercise: String,
+        file: String,
+        limits: checker::Limits,
+    },
+    Watch,
+    Shell,
+    Curriculum {
+        dot: bool,
+    },
+    Quiz(Option<String>),
+    Search(String),
+    Golden {
+        bless: bool,
+        selector: Option<String>,
+    },
+    Report {
+        format: report::Format,
+        output: Option<String>,
+        selector: Option<String>,
+    },
+    // Runs examples without any decoration, for `golden` to capture their output.
+    Exec(String),
+    Book(Option<String>),
+    Help,
+}
+
+impl Command {
+    pub fn parse<I>(args: I) -> Result<Self, String>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut args = args.into_iter();
+
+        let command = match args.next().as_deref() {
+            None | Some("help") | Some("-h") | Some("--help") => Command::Help,
+            Some("list") => Command::List,
+            Some("run") => {
+                let mut stats = false;
+                let mut selector = None;
+                for arg in args.by_ref() {
+                    match arg.as_str() {
+                        "--stats" => stats = true,
+                        _ if selector.is_none() => selector = Some(arg),
+                        _ => return Err(format!("unexpected argument `{}`", arg)),
+                    }
+                }
+                match selector {
+                    Some(selector) => Command::Run { selector, stats },
+                    None => return Err("`run` expects a topic or a `topic::example`".to_string()),
+                }
+            }
+            Some("grade") => Command::Grade(args.next()),
+            Some("progress") => match args.next().as_deref() {
+                None => Command::Progress { all: false },
+                Some("--all") => Command::Progress { all: true },
+                Some(other) => return Err(format!("unexpected argument `{}`", other)),
+            },
+            Some("hint") => match args.next() {
+                Some(exercise) => Command::Hint(exercise),
+                None => return Err("`hint` expects a `topic::exercise`".to_string()),
+            },
+            Some("check") => {
+                let (Some(exercise), Some(file)) = (args.next(), args.next()) else {
+                    return Err("`check` expects a `topic::exercise` and a file".to_string());
+                };
+                let mut limits = checker::Limits::default();
+                while let Some(arg) = args.next() {
+                    let value = args.next().and_then(|value| value.parse::<u64>().ok());
+                    match (arg.as_str(), value) {
+                        ("--timeout", Some(secs)) => limits.timeout = Duration::from_secs(secs),
+                        ("--memory", Some(mb)) => limits.memory_mb = mb,
+                        ("--timeout" | "--memory", None) => {
+                            return Err(format!("`{}` expects a number", arg))
+                        }
+                        _ => return Err(format!("unexpected argument `{}`", arg)),
+                    }
+                }
+                Command::Check {
+                    exercise,
+                    file,
+                    limits,
+                }
+            }
+            Some("watch") => Command::Watch,
+            Some("shell") => Command::Shell,
+            Some("curriculum") => match args.next().as_deref() {
+                None => Command::Curriculum { dot: false },
+                Some("--dot") => Command::Curriculum { dot: true },
+                Some(other) => return Err(format!("unexpected argument `{}`", other)),
+            },
+            Some("quiz") => Command::Quiz(args.next()),
+            Some("search") => {
+                let words: Vec<String> = args.by_ref().collect();
+                if words.is_empty() {
+                    return Err("`search` expects a term".to_string());
+                }
+                Command::Search(words.join(" "))
+            }
+            Some("golden") => {
+                let mut bless = false;
+                let mut selector = None;
+                for arg in args.by_ref() {
+                    match arg.as_str() {
+                        "--bless" => bless = true,
+                        _ if selector.is_none() => selector = Some(arg),
+                        _ => return Err(format!("unexpected argument `{}`", arg)),
+                    }
+                }
+                Command::Golden { bless, selector }
+            }
+            Some("report") => {
+                let mut format = report::Format::JsonLines;
+                let (mut output, mut selector) = (None, None);
+                while let Some(arg) = args.next() {
+                    match arg.as_str() {
+                        "--format" => match args.next() {
+                            Some(name) => format = report::Format::parse(&name)?,
+                            None => return Err("`--format` expects jsonl or junit".to_string()),
+                        },
+                        "--output" => match args.next() {
+                            Some(path) => output = Some(path),
+                            None => return Err("`--output` expects a file".to_string()),
+                        },
+                        _ if selector.is_none() => selector = Some(arg),
+                        _ => return Err(format!("unexpected argument `{}`", arg)),
+                    }
+                }
+                Command::Report {
+                    format,
+                    output,
+                    selector,
+                }
+            }
+            Some("exec") => match args.next() {
+                Some(selector) => Command::Exec(selector),
+                None => return Err("`exec` expects a topic or a `topic::example`".to_string()),
+            },
+            Some("book") => Command::Book(args.next()),
+            Some(other) => return Err(format!("unknown command `{}`", other)),
+        };
+
+        match args.next() {
+            Some(extra) => Err(format!("unexpected argument `{}`", extra)),
+            None => Ok(command),
+        }
+    }
+}
+
+pub const USAGE: &str = "\
+Usage: rust-trainning <command>
+
+Commands:
+    list                      List every topic and its examples
+    run <topic>               Run all the examples of a topic
+    run <topic>::<example>    Run a single example
+    run --stats <selector>    Also report the time and the allocations of every example
+    grade [<topic>[::<id>]]   Grade the exercises, all of them by default
+    progress [--all]          Show your progress and the next exercise to solve (--all: every learner)
+    hint <topic>::<exercise>  Reveal the next hint of an exercise
+    check <topic>::<exercise> <file> [--timeout <secs>] [--memory <MB>]
+                              Compile your own solution and run the exercise against it, in a sandbox
+    watch                     Rebuild and re-run a topic every time its file is saved
+    shell                     Browse, show and run the lessons interactively
+    curriculum [--dot]        Show the topics in the order of their prerequisites (or as a Graphviz graph)
+    quiz [<topic>]            Answer questions about what lesson snippets evaluate to
+    search <term>             Find the lessons that mention a term
+    golden [--bless] [<sel>]  Compare the examples' output with tests/golden (--bless updates it)
+    report [--format jsonl|junit] [--output <file>] [<topic>[::<id>]]
+                              Run the examples and write JSON Lines or JUnit XML results
+    book [<dir>]              Write the lessons as a Markdown book (into `book/` by default)
+    help                      Show this message";
+
+// Returns the process exit code.
+pub fn main<I>(args: I) -> i32
+where
+    I: IntoIterator<Item = String>,
+{
+    let command = match Command::parse(args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {}\n\n{}", e, USAGE);
+            return 2;
+        }
+    };
+
+    match command {
+        Command::Help => println!("{}", USAGE),
+        Command::List => list(),
+        Command::Run { selector, stats } => match registry::select(&selector) {
+            Ok(examples) => {
+                explain::install();
+                examples.into_iter().for_each(|example| run(example, stats));
+            }
+            Err(e) => {
+                eprintln!("error: {} (try `list`)", e);
+                return 1;
+            }
+        },
+        Command::Grade(selector) => return grade(selector.as_deref()),
+        Command::Progress { all } => match Progress::load(Progress::default_path()) {
+            Ok(progress) if all => progress::print_class_report(&progress),
+            Ok(progress) => progress::print_report(&progress::current_learner(), &progress),
+            Err(e) => {
+                eprintln!("error: cannot read the progress file: {}", e);
+                return 1;
+            }
+        },
+        Command::Hint(exercise) => return hint(&exercise),
+        Command::Check {
+            exercise,
+            file,
+            limits,
+        } => return check(&exercise, Path::new(&file), limits),
+        Command::Watch => {
+            if let Err(e) = watch::watch(&watch::project_root()) {
+                eprintln!("error: watch stopped: {}", e);
+                return 1;
+            }
+        }
+        Command::Curriculum { dot: true } => print!("{}", curriculum::dot(registry::topics())),
+        Command::Curriculum { dot: false } => match curriculum::curriculum() {
+            Ok(topics) => curriculum::print(&topics),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return 1;
+            }
+        },
+        Command::Quiz(topic) => return quiz(topic.as_deref()),
+        Command::Search(term) => search::print_results(&term, &search::search(&term)),
+        Command::Golden { bless, selector } => return golden(selector.as_deref(), bless),
+        Command::Report {
+            format,
+            output,
+            selector,
+        } => return report(format, output.as_deref(), selector.as_deref()),
+        Command::Exec(selector) => match registry::select(&selector) {
+            Ok(examples) => examples.into_iter().for_each(|example| (example.run)()),
+            Err(e) => {
+                eprintln!("error: {}", e);
+                return 1;
+            }
+        },
+        Command::Shell => {
+            explain::install();
+            if let Err(e) = shell::run_shell() {
+                eprintln!("error: shell stopped: {}", e);
+                return 1;
+            }
+        }
+        Command::Book(dir) => {
+            let dir = dir.unwrap_or_else(|| book::DEFAULT_DIR.to_string());
+            match book::write(Path::new(&dir)) {
+                Ok(files) => println!("Wrote {} files into {}", files.len(), dir),
+                Err(e) => {
+                    eprintln!("error: cannot write the book: {}", e);
+                    return 1;
+                }
+            }
+        }
+    }
+
+    0
+}
+
+fn list() {
+    for topic in registry::topics() {
+        println!("{}", topic.name);
+
+        let width = topic.examples.iter().map(|e| e.id.len()).max().unwrap_or(0);
+        for example in topic.examples {
+            println!(
+                "    {:width$}  {}",
+                example.id,
+                example.title,
+                width = width
+            );
+        }
+    }
+}
+
+fn golden(selector: Option<&str>, bless: bool) -> i32 {
+    let examples = match selector {
+        Some(selector) => match registry::select(selector) {
+            Ok(examples) => examples,
+            Err(e) => {
+                eprintln!("error: {} (try `list`)", e);
+                return 1;
+            }
+        },
+        None => registry::examples().collect(),
+    };
+    let binary = match env::current_exe() {
+        Ok(binary) => binary,
+        Err(e) => {
+            eprintln!("error: cannot find the running binary: {}", e);
+            return 1;
+        }
+    };
+    let dir = watch::project_root().join("tests/golden");
+
+    let (mut matching, mut failed, mut skipped) = (0, 0, 0);
+    for &example in &examples {
+        let status = match golden::check(&binary, &dir, example, bless) {
+            Ok(status) => status,
+            Err(e) => {
+                eprintln!("error: {}: {}", example.path(), e);
+                return 1;
+            }
+        };
+
+        match status {
+            Status::Matches => matching += 1,
+            Status::Blessed => println!("blessed  {}", example.path()),
+            Status::Skipped => skipped += 1,
+            Status::Missing => {
+                failed += 1;
+                println!("missing  {} (run `golden --bless`)", example.path());
+            }
+            Status::Differs { expected, actual } => {
+                failed += 1;
+                println!("FAIL     {}", example.path());
+                golden::print_diff(&expected, &actual);
+            }
+        }
+    }
+
+    // Only a full run knows which files nobody uses.
+    if selector.is_none() {
+        let stale = golden::stale_files(&dir, &examples).unwrap_or_default();
+        for path in stale {
+            if bless {
+                println!("removed  {}", path.display());
+                let _ = std::fs::remove_file(path);
+            } else {
+                failed += 1;
+                println!("stale    {} (run `golden --bless`)", path.display());
+            }
+        }
+    }
+
+    println!(
+        "\n{} examples, {} match, {} failed, {} not compared",
+        examples.len(),
+        matching,
+        failed,
+        skipped
+    );
+    if failed > 0 {
+        1
+    } else {
+        0
+    }
+}
+
+// The report is the result: failing examples don't change the exit code, only errors running them do.
+fn report(format: report::Format, output: Option<&str>, selector: Option<&str>) -> i32 {
+    let examples = match selector {
+        Some(selector) => match registry::select(selector) {
+            Ok(examples) => examples,
+            Err(e) => {
+                eprintln!("error: {} (try `list`)", e);
+                return 1;
+            }
+        },
+        None => registry::examples().collect(),
+    };
+    let binary = match env::current_exe() {
+        Ok(binary) => binary,
+        Err(e) => {
+            eprintln!("error: cannot find the running binary: {}", e);
+            return 1;
+        }
+    };
+
+    let mut records = Vec::with_capacity(examples.len());
+    for example in examples {
+        match report::run(&binary, example) {
+            Ok(record) => records.push(record),
+            Err(e) => {
+                eprintln!("error: {}: {}", example.path(), e);
+                return 1;
+            }
+        }
+    }
+
+    let learner = progress::current_learner();
+    let text = match format {
+        report::Format::JsonLines => report::json_lines(&records, &learner),
+        report::Format::Junit => report::junit(&records, &learner),
+    };
+
+    let written = match output {
+        Some(path) => std::fs::write(path, text),
+        None => io::stdout().write_all(text.as_bytes()),
+    };
+    if let Err(e) = written {
+        eprintln!("error: cannot write the report: {}", e);
+        return 1;
+    }
+    0
+}
+
+fn quiz(topic: Option<&str>) -> i32 {
+    let questions = match quiz::questions(topic) {
+        Ok(questions) => questions,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return 1;
+        }
+    };
+
+    let stdin = io::stdin();
+    match quiz::ask(&questions, &mut stdin.lock(), &mut io::stdout()) {
+        Ok(_) => 0,
+        Err(e) => {
+            eprintln!("error: quiz stopped: {}", e);
+            1
+        }
+    }
+}
+
+fn run(example: &Example, stats: bool) {
+    println!("==> {} ({})", example.path(), example.title);
+    if stats {
+        let measure = alloc_stats::measure(example.run);
+        println!("--- {}", alloc_stats::summary(&measure));
+    } else {
+        (example.run)();
+    }
+    println!();
+}
+
+fn grade(selector: Option<&str>) -> i32 {
+    let examples = match selector {
+        Some(selector) => match registry::select(selector) {
+            Ok(examples) => examples,
+            Err(e) => {
+                eprintln!("error: {} (try `list`)", e);
+                return 1;
+            }
+        },
+        None => registry::examples().collect(),
+    };
+    let exercises: Vec<_> = examples.into_iter().filter(|e| e.exercise).collect();
+
+    if exercises.is_empty() {
+        eprintln!("error: no exercises to grade");
+        return 1;
+    }
+
+    let grader = Grader::new();
+    let grades: Vec<Grade> = exercises
+        .into_iter()
+        .map(|example| {
+            println!("==> {} ({})", example.path(), example.title);
+            let grade = grader.grade(example);
+            print_grade(&grade);
+            grade
+        })
+        .collect();
+    drop(grader);
+
+    harness::print_summary(&grades);
+    record_progress(&grades);
+    harness::exit_code(&grades)
+}
+
+fn record_progress(grades: &[Grade]) {
+    let path = Progress::default_path();
+    let saved = Progress::load(path).and_then(|mut progress| {
+        let learner = progress.learner_mut(&progress::current_learner());
+        for grade in grades {
+            learner.record(grade.example, grade.passed());
+        }
+        progress.save()
+    });
+
+    if let Err(e) = saved {
+        eprintln!("warning: progress was not saved: {}", e);
+    }
+}
+
+// Every call reveals one more hint, and prints all the ones revealed so far.
+fn hint(selector: &str) -> i32 {
+    let example = match registry::select(selector) {
+        Ok(examples) if selector.contains("::") => examples[0],
+        Ok(_) => {
+            eprintln!("error: `hint` expects a single exercise, like `string::exercise_1`");
+            return 1;
+        }
+        Err(e) => {
+            eprintln!("error: {} (try `list`)", e);
+            return 1;
+        }
+    };
+    if example.hints.is_empty() {
+        println!(
+            "There are no hints for {}, the comments around it are all there is.",
+            example.path()
+        );
+        return 0;
+    }
+
+    let name = progress::current_learner();
+    let revealed = Progress::load(Progress::default_path()).and_then(|mut progress| {
+        let revealed = progress.learner_mut(&name).reveal_hint(example);
+        progress.save()?;
+        Ok(revealed)
+    });
+    let revealed = match revealed {
+        Ok(revealed) => revealed,
+        Err(e) => {
+            eprintln!("error: cannot record the hint in the progress file: {}", e);
+            return 1;
+        }
+    };
+
+    println!("Hints for {} ({})\n", example.path(), example.title);
+    for (i, hint) in example.hints[..revealed].iter().enumerate() {
+        println!("{}/{}  {}", i + 1, example.hints.len(), hint);
+    }
+    if revealed < example.hints.len() {
+        println!(
+            "\nStill stuck? `hint {}` again for the next one.",
+            example.path()
+        );
+    } else {
+        println!("\nThat was the last hint.");
+    }
+    0
+}
+
+fn check(selector: &str, file: &Path, limits: checker::Limits) -> i32 {
+    let exercise = match registry::select(selector) {
+        Ok(examples) if selector.contains("::") && examples[0].exercise => examples[0],
+        Ok(_) => {
+            eprintln!("error: `check` expects a single exercise, like `string::exercise_1`");
+            return 1;
+        }
+        Err(e) => {
+            eprintln!("error: {} (try `list`)", e);
+            return 1;
+        }
+    };
+    let toolchain = match env::current_exe()
+        .map_err(|e| format!("cannot find the running binary: {}", e))
+        .and_then(|binary| checker::Toolchain::locate(&binary))
+    {
+        Ok(toolchain) => toolchain,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return 1;
+        }
+    };
+
+    println!(
+        "==> {} against {} ({})",
+        file.display(),
+        exercise.path(),
+        exercise.title
+    );
+    let outcome = match checker::check(&toolchain, exercise, file, limits) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            return 1;
+        }
+    };
+
+    for line in outcome.stdout.lines() {
+        println!("     | {}", line);
+    }
+    match &outcome.verdict {
+        Verdict::Passed => println!("PASS ({:.2}s)", outcome.duration.as_secs_f64()),
+        Verdict::Failed(message) => println!("FAIL {}", message.replace('\n', "\n     ")),
+        Verdict::Panicked(message) => println!("PANIC {}", message.replace('\n', "\n      ")),
+        Verdict::DoesNotCompile(errors) => println!("DOES NOT COMPILE\n{}", errors.trim_end()),
+        Verdict::TimedOut => println!("TIMEOUT after {}s", limits.timeout.as_secs()),
+        Verdict::OutOfMemory => println!("OUT OF MEMORY, the limit is {} MB", limits.memory_mb),
+        Verdict::Crashed(reason) => println!("CRASHED, {}", reason),
+    }
+
+    if outcome.verdict == Verdict::Passed {
+        0
+    } else {
+        1
+    }
+}
+
+fn print_grade(grade: &Grade) {
+    match &grade.outcome {
+        Outcome::Passed => println!("PASS\n"),
+        Outcome::Failed(failure) => {
+            println!("FAIL {}", failure.message.replace('\n', "\n     "));
+            if let Some(location) = &failure.location {
+                println!("     at {}", location);
+            }
+            if let Some(text) = explain::explain(&failure.message) {
+                println!("{}", text);
+            }
+            println!();
+        }
+    }
+}
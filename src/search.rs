@@ -0,0 +1,194 @@
+// # Lesson Search
+//
+// `search <term>` looks for a word in every lesson: the comments, the names of the functions and types
+// they define (`_Fibonacci`, `CliError`, `EvenNum`, `Viking`...) and the code itself (`Box::leak`).
+// The search is case-insensitive and matches substrings.
+//
+// Matches are grouped by the function (or type) they belong to, so one example shows up once, and ranked:
+//
+// - The term is the name of a function or type    -> 100
+// - The term is part of a name                      -> 50
+// - The term is in a heading of the comments        -> 40
+// - The term is in a comment                        -> 30
+// - The term is in the code                         -> 10
+//
+// A group scores its best match, plus a little for every other matching line.
+
+use std::collections::BTreeMap;
+
+use crate::lesson::{self, Item, ItemKind};
+use crate::registry::{self, Topic};
+
+pub const MAX_RESULTS: usize = 20;
+const SNIPPET_WIDTH: usize = 100;
+
+pub struct Hit {
+    pub topic: &'static Topic,
+    // The function or type the match is in (`Viking::new` for methods), `None` for module-level comments.
+    pub item: Option<String>,
+    // 1-based, as editors and compilers show them.
+    pub line: usize,
+    pub score: u32,
+    pub snippet: String,
+}
+
+impl Hit {
+    pub fn path(&self) -> String {
+        match &self.item {
+            Some(item) => format!("{}::{}", self.topic.name, item),
+            None => self.topic.name.to_string(),
+        }
+    }
+
+    pub fn location(&self) -> String {
+        format!("src/topics/{}.rs:{}", self.topic.name, self.line)
+    }
+}
+
+pub fn search(term: &str) -> Vec<Hit> {
+    let term = term.trim().to_lowercase();
+    if term.is_empty() {
+        return Vec::new();
+    }
+
+    let mut hits: Vec<Hit> = registry::topics()
+        .iter()
+        .flat_map(|topic| search_topic(topic, &term))
+        .collect();
+
+    hits.sort_by(|a, b| {
+        b.score
+            .cmp(&a.score)
+            .then(a.topic.name.cmp(b.topic.name))
+            .then(a.line.cmp(&b.line))
+    });
+    hits
+}
+
+fn search_topic(topic: &'static Topic, term: &str) -> Vec<Hit> {
+    let items = lesson::items(topic.source);
+    // (item start line, or the line itself for module-level comments) -> best hit and its match count
+    let mut groups: BTreeMap<usize, (Hit, u32)> = BTreeMap::new();
+
+    for (number, line) in lesson::body(topic.source).lines().enumerate() {
+        let lowercase = line.to_lowercase();
+        let Some(column) = lowercase.find(term) else {
+            continue;
+        };
+
+        let item = lesson::item_at(&items, number);
+        let trimmed = line.trim_start();
+        let score = match item {
+            Some(item) if item.start == number && item.kind != ItemKind::Impl => {
+                if item.name.eq_ignore_ascii_case(term) {
+                    100
+                } else if item.name.to_lowercase().contains(term) {
+                    50
+                } else {
+                    10
+                }
+            }
+            _ => match lesson::prose_line(trimmed) {
+                Some(text) if text.starts_with('#') => 40,
+                Some(_) => 30,
+                None => 10,
+            },
+        };
+
+        let key = item.map_or(number, |item| item.start);
+        let hit = Hit {
+            topic,
+            item: item.map(|item| qualified_name(&items, item)),
+            line: number + 1,
+            score,
+            snippet: snippet(line, column, term.len()),
+        };
+
+        match groups.get_mut(&key) {
+            Some((best, matches)) => {
+                *matches += 1;
+                if hit.score > best.score {
+                    *best = hit;
+                }
+            }
+            None => {
+                groups.insert(key, (hit, 1));
+            }
+        }
+    }
+
+    groups
+        .into_values()
+        .map(|(mut hit, matches)| {
+            hit.score += (matches - 1).min(10) * 2;
+            hit
+        })
+        .collect()
+}
+
+// Methods are named after the type of their impl block: `Viking::new`, `_Fibonacci::next`.
+fn qualified_name(items: &[Item], item: &Item) -> String {
+    let owner = items.iter().find(|other| {
+        other.kind == ItemKind::Impl && other.start < item.start && item.end <= other.end
+    });
+
+    match owner {
+        Some(owner) if item.kind == ItemKind::Function => {
+            let target = owner.name.rsplit(" for ").next().unwrap_or(owner.name);
+            format!("{}::{}", target, item.name)
+        }
+        _ => item.name.to_string(),
+    }
+}
+
+// The line, trimmed to about `SNIPPET_WIDTH` characters around the match.
+fn snippet(line: &str, column: usize, len: usize) -> String {
+    let line = line.trim_end();
+    let indent = line.len() - line.trim_start().len();
+    let (column, line) = (column.saturating_sub(indent), line.trim_start());
+    if line.chars().count() <= SNIPPET_WIDTH {
+        return line.to_string();
+    }
+
+    // `column` is a byte offset in the lowercase line, which only differs for a few non-ASCII letters:
+    // clamp it to a char boundary of the original.
+    let floor = |mut i: usize| {
+        i = i.min(line.len());
+        while !line.is_char_boundary(i) {
+            i -= 1;
+        }
+        i
+    };
+    let context = SNIPPET_WIDTH.saturating_sub(len) / 2;
+    let start = floor(column.saturating_sub(context));
+    let end = floor(start + SNIPPET_WIDTH);
+
+    let mut snippet = String::new();
+    if start > 0 {
+        snippet.push_str("...");
+    }
+    snippet.push_str(&line[start..end]);
+    if end < line.len() {
+        snippet.push_str("...");
+    }
+    snippet
+}
+
+pub fn print_results(term: &str, hits: &[Hit]) {
+    if hits.is_empty() {
+        println!("No lesson mentions `{}`.", term);
+        return;
+    }
+
+    for (rank, hit) in hits.iter().take(MAX_RESULTS).enumerate() {
+        println!("{:>2}. {}  ({})", rank + 1, hit.path(), hit.location());
+        println!("    {}", hit.snippet);
+    }
+
+    if hits.len() > MAX_RESULTS {
+        println!(
+            "\n{} more results, try a more specific term.",
+            hits.len() - MAX_RESULTS
+        );
+    }
+}
@@ -0,0 +1,356 @@
+// # Line Editor
+//
+// A small readline for the shell, on top of the terminal's raw mode (`stty`), so nothing outside of std is
+// needed:
+//
+// - Left/Right, Home/End (or Ctrl-A/Ctrl-E) move the cursor, Backspace/Delete remove a character.
+// - Up/Down walk through the history of the session.
+// - Ctrl-U/Ctrl-K cut the line before/after the cursor, Ctrl-W the word before it.
+// - Tab completes the word under the cursor with the shell's completer.
+// - Ctrl-C clears the line, Ctrl-D on an empty line ends the input.
+// - When the terminal goes away (the input ends for real), the line typed so far is returned as if Enter
+//   had been pressed, then the input ends.
+//
+// When stdin is not a terminal (a pipe, a file, a test), lines are read as they are, without editing.
+
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+use std::process::{Command, Stdio};
+
+pub struct Editor {
+    history: Vec<String>,
+    raw: bool,
+}
+
+// A terminal switched to raw mode, switched back when dropped.
+struct RawMode {
+    saved: String,
+}
+
+impl RawMode {
+    fn enable() -> io::Result<Self> {
+        let saved = stty(&["-g"])?;
+        stty(&["-icanon", "-echo", "-isig", "-ixon", "min", "1"])?;
+        Ok(Self {
+            saved: saved.trim().to_string(),
+        })
+    }
+}
+
+impl Drop for RawMode {
+    fn drop(&mut self) {
+        let _ = stty(&[&self.saved]);
+    }
+}
+
+fn stty(args: &[&str]) -> io::Result<String> {
+    let output = Command::new("stty")
+        .args(args)
+        .stdin(Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err(io::Error::other("stty failed"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+enum Key {
+    Char(char),
+    Enter,
+    Backspace,
+    Delete,
+    Left,
+    Right,
+    Up,
+    Down,
+    Home,
+    End,
+    Tab,
+    CutBefore,
+    CutAfter,
+    CutWord,
+    Cancel,
+    // Ctrl-D: ends the input on an empty line, deletes the character under the cursor otherwise.
+    CtrlD,
+    // Nothing more to read, e.g. the terminal hung up.
+    Closed,
+    Ignored,
+}
+
+impl Default for Editor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Editor {
+    pub fn new() -> Self {
+        Self {
+            history: Vec::new(),
+            raw: io::stdin().is_terminal() && stty(&["-g"]).is_ok(),
+        }
+    }
+
+    pub fn history(&self) -> &[String] {
+        &self.history
+    }
+
+    // Returns `None` at the end of the input.
+    pub fn read_line<F>(&mut self, prompt: &str, complete: F) -> io::Result<Option<String>>
+    where
+        F: Fn(&str) -> Vec<String>,
+    {
+        let line = if self.raw {
+            self.read_raw(prompt, complete)?
+        } else {
+            print!("{}", prompt);
+            io::stdout().flush()?;
+            let mut line = String::new();
+            match io::stdin().lock().read_line(&mut line)? {
+                0 => None,
+                _ => Some(line.trim_end_matches(['\n', '\r']).to_string()),
+            }
+        };
+
+        if let Some(line) = &line {
+            let trimmed = line.trim();
+            if !trimmed.is_empty() && self.history.last().map(String::as_str) != Some(trimmed) {
+                self.history.push(trimmed.to_string());
+            }
+        }
+        Ok(line)
+    }
+
+    fn read_raw<F>(&mut self, prompt: &str, complete: F) -> io::Result<Option<String>>
+    where
+        F: Fn(&str) -> Vec<String>,
+    {
+        let _raw = RawMode::enable()?;
+        self.edit(prompt, complete, &mut io::stdin().lock(), &mut io::stdout())
+    }
+
+    // The editing loop of `read_raw`, on any input and output: key presses come in as the bytes a terminal
+    // in raw mode sends, the line is echoed with the escape codes it understands. `read_line` adds the
+    // line to the history, `edit` doesn't.
+    pub fn edit<F>(
+        &self,
+        prompt: &str,
+        complete: F,
+        stdin: &mut impl Read,
+        stdout: &mut impl Write,
+    ) -> io::Result<Option<String>>
+    where
+        F: Fn(&str) -> Vec<String>,
+    {
+        let mut line: Vec<char> = Vec::new();
+        let mut cursor = 0;
+        // `history.len()` is the line being typed, which is kept aside while browsing.
+        let mut browsing = self.history.len();
+        let mut draft: Vec<char> = Vec::new();
+
+        redraw(stdout, prompt, &line, cursor)?;
+        loop {
+            match read_key(stdin)? {
+                Key::Char(c) => {
+                    line.insert(cursor, c);
+                    cursor += 1;
+                }
+                Key::Enter => {
+                    write!(stdout, "\r\n")?;
+                    stdout.flush()?;
+                    return Ok(Some(line.into_iter().collect()));
+                }
+                Key::CtrlD if line.is_empty() => {
+                    write!(stdout, "\r\n")?;
+                    stdout.flush()?;
+                    return Ok(None);
+                }
+                // The next read ends the input, with the line empty.
+                Key::Closed => {
+                    write!(stdout, "\r\n")?;
+                    stdout.flush()?;
+                    return Ok((!line.is_empty()).then(|| line.into_iter().collect()));
+                }
+                Key::CtrlD | Key::Delete if cursor < line.len() => {
+                    line.remove(cursor);
+                }
+                Key::Backspace if cursor > 0 => {
+                    cursor -= 1;
+                    line.remove(cursor);
+                }
+                Key::Left if cursor > 0 => cursor -= 1,
+                Key::Right if cursor < line.len() => cursor += 1,
+                Key::Home => cursor = 0,
+                Key::End => cursor = line.len(),
+                Key::Up if browsing > 0 => {
+                    if browsing == self.history.len() {
+                        draft = line.clone();
+                    }
+                    browsing -= 1;
+                    line = self.history[browsing].chars().collect();
+                    cursor = line.len();
+                }
+                Key::Down if browsing < self.history.len() => {
+                    browsing += 1;
+                    line = match self.history.get(browsing) {
+                        Some(entry) => entry.chars().collect(),
+                        None => draft.clone(),
+                    };
+                    cursor = line.len();
+                }
+                Key::CutBefore => {
+                    line.drain(..cursor);
+                    cursor = 0;
+                }
+                Key::CutAfter => line.truncate(cursor),
+                Key::CutWord => {
+                    let mut start = cursor;
+                    while start > 0 && line[start - 1] == ' ' {
+                        start -= 1;
+                    }
+                    while start > 0 && line[start - 1] != ' ' {
+                        start -= 1;
+                    }
+                    line.drain(start..cursor);
+                    cursor = start;
+                }
+                Key::Cancel => {
+                    write!(stdout, "^C\r\n")?;
+                    line.clear();
+                    cursor = 0;
+                    browsing = self.history.len();
+                }
+                Key::Tab => {
+                    let before: String = line[..cursor].iter().collect();
+                    let word_start = before.rfind(' ').map_or(0, |i| i + 1);
+                    let word = &before[word_start..];
+                    let candidates = complete(&before);
+
+                    match common_prefix(&candidates) {
+                        Some(prefix) if prefix.len() > word.len() => {
+                            let added: Vec<char> = prefix[word.len()..].chars().collect();
+                            let end = cursor + added.len();
+                            line.splice(cursor..cursor, added);
+                            cursor = end;
+                            if candidates.len() == 1 {
+                                line.insert(cursor, ' ');
+                                cursor += 1;
+                            }
+                        }
+                        _ if candidates.len() > 1 => {
+                            write!(stdout, "\r\n{}\r\n", candidates.join("  "))?;
+                        }
+                        _ => {}
+                    }
+                }
+                _ => {}
+            }
+            redraw(stdout, prompt, &line, cursor)?;
+        }
+    }
+}
+
+fn redraw(out: &mut impl Write, prompt: &str, line: &[char], cursor: usize) -> io::Result<()> {
+    let text: String = line.iter().collect();
+    write!(out, "\r{}{}\x1b[K", prompt, text)?;
+    if cursor < line.len() {
+        write!(out, "\x1b[{}D", line.len() - cursor)?;
+    }
+    out.flush()
+}
+
+fn read_byte(input: &mut impl Read) -> io::Result<Option<u8>> {
+    let mut byte = [0];
+    match input.read(&mut byte)? {
+        0 => Ok(None),
+        _ => Ok(Some(byte[0])),
+    }
+}
+
+fn read_key(input: &mut impl Read) -> io::Result<Key> {
+    let Some(byte) = read_byte(input)? else {
+        return Ok(Key::Closed);
+    };
+
+    let key = match byte {
+        b'\r' | b'\n' => Key::Enter,
+        0x7f | 0x08 => Key::Backspace,
+        b'\t' => Key::Tab,
+        0x01 => Key::Home,
+        0x05 => Key::End,
+        0x02 => Key::Left,
+        0x06 => Key::Right,
+        0x10 => Key::Up,
+        0x0e => Key::Down,
+        0x15 => Key::CutBefore,
+        0x0b => Key::CutAfter,
+        0x17 => Key::CutWord,
+        0x03 => Key::Cancel,
+        0x04 => Key::CtrlD,
+        0x1b => read_escape(input)?,
+        byte if byte < 0x20 => Key::Ignored,
+        byte => {
+            // The first byte of a UTF-8 sequence tells how many follow.
+            let len = match byte {
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf7 => 4,
+                _ => 1,
+            };
+            let mut bytes = vec![byte];
+            for _ in 1..len {
+                bytes.extend(read_byte(input)?);
+            }
+            match std::str::from_utf8(&bytes)
+                .ok()
+                .and_then(|s| s.chars().next())
+            {
+                Some(c) => Key::Char(c),
+                None => Key::Ignored,
+            }
+        }
+    };
+    Ok(key)
+}
+
+// `ESC [ A` (arrows), `ESC [ 3 ~` (Delete), `ESC O H` (Home on some terminals)...
+fn read_escape(input: &mut impl Read) -> io::Result<Key> {
+    let Some(b'[' | b'O') = read_byte(input)? else {
+        return Ok(Key::Ignored);
+    };
+
+    let mut code = Vec::new();
+    loop {
+        match read_byte(input)? {
+            Some(b @ b'0'..=b'9') | Some(b @ b';') => code.push(b),
+            Some(last) => {
+                let key = match (code.as_slice(), last) {
+                    (_, b'A') => Key::Up,
+                    (_, b'B') => Key::Down,
+                    (_, b'C') => Key::Right,
+                    (_, b'D') => Key::Left,
+                    (_, b'H') | (b"1" | b"7", b'~') => Key::Home,
+                    (_, b'F') | (b"4" | b"8", b'~') => Key::End,
+                    (b"3", b'~') => Key::Delete,
+                    _ => Key::Ignored,
+                };
+                return Ok(key);
+            }
+            None => return Ok(Key::Ignored),
+        }
+    }
+}
+
+fn common_prefix(words: &[String]) -> Option<String> {
+    let first = words.first()?;
+    let mut len = first.len();
+    for word in &words[1..] {
+        len = first
+            .char_indices()
+            .zip(word.chars())
+            .take_while(|((_, a), b)| a == b)
+            .last()
+            .map_or(0, |((i, a), _)| i + a.len_utf8())
+            .min(len);
+    }
+    Some(first[..len].to_string())
+}
@@ -0,0 +1,69 @@
+// # Function Index
+//
+// The shell walks through a topic one function at a time, so it keeps its own index of the functions each
+// lesson defines at the top level (methods and nested functions are shown with the function around them),
+// in source order, with their comment block and their source.
+
+use crate::lesson::{self, ItemKind};
+use crate::registry::{Example, Topic};
+
+pub struct Function {
+    pub topic: &'static Topic,
+    pub name: &'static str,
+    // The comment block right above the function.
+    pub doc: Vec<&'static str>,
+    pub source: Vec<&'static str>,
+    // Line of `fn` in the topic's file, 1-based.
+    pub line: usize,
+}
+
+impl Function {
+    // The registered example with the same name, if any.
+    pub fn example(&self) -> Option<&'static Example> {
+        self.topic.example(self.name)
+    }
+}
+
+pub struct Index {
+    functions: Vec<Function>,
+}
+
+impl Index {
+    pub fn new(topics: &'static [Topic]) -> Self {
+        let mut functions = Vec::new();
+
+        for topic in topics {
+            let lines: Vec<&'static str> = lesson::body(topic.source).lines().collect();
+            let items = lesson::items(topic.source);
+
+            for item in &items {
+                let nested = items
+                    .iter()
+                    .any(|other| other.start < item.start && item.end <= other.end);
+                if item.kind != ItemKind::Function || nested {
+                    continue;
+                }
+
+                functions.push(Function {
+                    topic,
+                    name: item.name,
+                    doc: lines[item.doc_start..item.start].to_vec(),
+                    source: lines[item.start..=item.end].to_vec(),
+                    line: item.start + 1,
+                });
+            }
+        }
+
+        Self { functions }
+    }
+
+    pub fn functions<'a>(&'a self, topic: &'a str) -> impl Iterator<Item = &'a Function> {
+        self.functions.iter().filter(move |f| f.topic.name == topic)
+    }
+
+    pub fn find(&self, topic: &str, name: &str) -> Option<&Function> {
+        self.functions
+            .iter()
+            .find(|f| f.topic.name == topic && f.name == name)
+    }
+}
@@ -0,0 +1,358 @@
+// # Shell
+//
+// An interactive shell to walk a class through a lesson without switching between an editor and a terminal:
+//
+// rust-trainning> cd closures
+// closures> ls
+// closures> show _example_closures_06     -> its comment block and source
+// closures> run                           -> runs the function being shown
+// closures> next                          -> shows the next function (and moves to the next topic at the end)
+//
+// The shell reads lines with its own `editor` (history, cursor keys, Tab completion) and finds functions in
+// its own `index`, built from the lessons' sources.
+
+pub mod editor;
+pub mod index;
+
+use std::io::{self, Write};
+use std::panic;
+
+use crate::curriculum;
+use crate::registry::{self, Example, Topic};
+
+use editor::Editor;
+use index::{Function, Index};
+
+pub const HELP: &str = "\
+Commands:
+    topics              List the topics
+    cd <topic>          Enter a topic (`cd` or `cd ..` to leave it)
+    ls                  List the functions of the topic (or the topics)
+    show [<function>]   Print a function with its comment block
+    run [<function>]    Run an example, the one being shown by default
+    next, prev          Show the next or the previous function
+    help                Show this message
+    exit                Leave the shell (or Ctrl-D)";
+
+const COMMANDS: &[&str] = &[
+    "topics", "cd", "ls", "show", "run", "next", "prev", "help", "exit",
+];
+
+pub enum Flow {
+    Continue,
+    Exit,
+}
+
+pub struct Shell {
+    index: Index,
+    topic: Option<&'static Topic>,
+    // The function being shown, as a position in the topic's functions.
+    position: Option<usize>,
+}
+
+impl Default for Shell {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Shell {
+    pub fn new() -> Self {
+        Self {
+            index: Index::new(registry::topics()),
+            topic: None,
+            position: None,
+        }
+    }
+
+    pub fn topic(&self) -> Option<&'static Topic> {
+        self.topic
+    }
+
+    pub fn current(&self) -> Option<&Function> {
+        let topic = self.topic?;
+        self.index.functions(topic.name).nth(self.position?)
+    }
+
+    pub fn prompt(&self) -> String {
+        match (self.topic, self.current()) {
+            (Some(topic), Some(function)) => format!("{}::{}> ", topic.name, function.name),
+            (Some(topic), None) => format!("{}> ", topic.name),
+            _ => "rust-trainning> ".to_string(),
+        }
+    }
+
+    pub fn execute(&mut self, line: &str, out: &mut impl Write) -> io::Result<Flow> {
+        let mut words = line.split_whitespace();
+        let Some(command) = words.next() else {
+            return Ok(Flow::Continue);
+        };
+        let argument = words.next();
+
+        match (command, argument) {
+            ("exit" | "quit", _) => return Ok(Flow::Exit),
+            ("help", _) => writeln!(out, "{}", HELP)?,
+            ("topics", _) => self.topics(out)?,
+            ("cd", None | Some("..") | Some("/")) => {
+                self.topic = None;
+                self.position = None;
+            }
+            ("cd", Some(name)) => match registry::topic(name) {
+                Some(topic) => self.enter(topic),
+                None => writeln!(out, "unknown topic `{}` (try `topics`)", name)?,
+            },
+            ("ls", _) => match self.topic {
+                Some(topic) => self.list(topic, out)?,
+                None => self.topics(out)?,
+            },
+            ("show", name) => match self.select(name) {
+                Ok(position) => {
+                    self.position = Some(position);
+                    self.show(out)?;
+                }
+                Err(e) => writeln!(out, "{}", e)?,
+            },
+            ("run", name) => match self.runnable(name) {
+                Ok(example) => run(example, out)?,
+                Err(e) => writeln!(out, "{}", e)?,
+            },
+            ("next", _) => self.step(true, out)?,
+            ("prev", _) => self.step(false, out)?,
+            (other, _) => writeln!(out, "unknown command `{}` (try `help`)", other)?,
+        }
+
+        Ok(Flow::Continue)
+    }
+
+    fn enter(&mut self, topic: &'static Topic) {
+        self.topic = Some(topic);
+        self.position = None;
+    }
+
+    fn topics(&self, out: &mut impl Write) -> io::Result<()> {
+        let width = registry::topics()
+            .iter()
+            .map(|t| t.name.len())
+            .max()
+            .unwrap_or(0);
+        for topic in registry::topics() {
+            let mark = if self.topic.is_some_and(|t| t.name == topic.name) {
+                '*'
+            } else {
+                ' '
+            };
+            writeln!(
+                out,
+                "{} {:width$}  {}",
+                mark,
+                topic.name,
+                topic.title,
+                width = width
+            )?;
+        }
+        Ok(())
+    }
+
+    fn list(&self, topic: &'static Topic, out: &mut impl Write) -> io::Result<()> {
+        writeln!(out, "{} ({})", topic.title, topic.name)?;
+
+        let functions: Vec<_> = self.index.functions(topic.name).collect();
+        let width = functions.iter().map(|f| f.name.len()).max().unwrap_or(0);
+        for (position, function) in functions.iter().enumerate() {
+            let mark = if self.position == Some(position) {
+                '>'
+            } else {
+                ' '
+            };
+            let runnable = match function.example() {
+                Some(example) => format!("run: {}", example.title),
+                None => String::new(),
+            };
+            writeln!(
+                out,
+                "{} {:width$}  {}",
+                mark,
+                function.name,
+                runnable,
+                width = width
+            )?;
+        }
+
+        // Examples registered as closures have no function of their own.
+        let others: Vec<_> = topic
+            .examples
+            .iter()
+            .filter(|e| self.index.find(topic.name, e.id).is_none())
+            .map(|e| e.id)
+            .collect();
+        if !others.is_empty() {
+            writeln!(out, "Also runnable: {}", others.join(", "))?;
+        }
+        Ok(())
+    }
+
+    // `show closures::_example_closures_06` also moves into `closures`.
+    fn select(&mut self, name: Option<&str>) -> Result<usize, String> {
+        let Some(name) = name else {
+            return self
+                .position
+                .filter(|_| self.topic.is_some())
+                .ok_or_else(|| {
+                    "nothing is shown yet, try `show <function>` or `next`".to_string()
+                });
+        };
+
+        let (topic, name) = match name.split_once("::") {
+            Some((topic, name)) => (
+                registry::topic(topic).ok_or_else(|| format!("unknown topic `{}`", topic))?,
+                name,
+            ),
+            None => (
+                self.topic
+                    .ok_or_else(|| "`cd` into a topic first (try `topics`)".to_string())?,
+                name,
+            ),
+        };
+
+        let position = self
+            .index
+            .functions(topic.name)
+            .position(|f| f.name == name)
+            .ok_or_else(|| format!("no function `{}` in `{}` (try `ls`)", name, topic.name))?;
+        if self.topic.is_none_or(|t| t.name != topic.name) {
+            self.enter(topic);
+        }
+        Ok(position)
+    }
+
+    fn runnable(&self, name: Option<&str>) -> Result<&'static Example, String> {
+        let name = match name {
+            Some(name) => name.to_string(),
+            None => match self.current() {
+                Some(function) => function.name.to_string(),
+                None => return Err("nothing is shown yet, try `run <function>`".to_string()),
+            },
+        };
+
+        if name.contains("::") {
+            return registry::select(&name).map(|examples| examples[0]);
+        }
+        let topic = self
+            .topic
+            .ok_or_else(|| "`cd` into a topic first (try `topics`)".to_string())?;
+        topic
+            .example(&name)
+            .ok_or_else(|| format!("`{}` is not a registered example of `{}`", name, topic.name))
+    }
+
+    fn show(&self, out: &mut impl Write) -> io::Result<()> {
+        let Some(function) = self.current() else {
+            return Ok(());
+        };
+
+        writeln!(
+            out,
+            "--- src/topics/{}.rs:{}",
+            function.topic.name, function.line
+        )?;
+        for line in function.doc.iter().chain(&function.source) {
+            writeln!(out, "{}", line)?;
+        }
+        if let Some(example) = function.example() {
+            writeln!(out, "--- `run` to see it: {}", example.title)?;
+        }
+        Ok(())
+    }
+
+    // `next` past the last function goes on with the next topic of the curriculum.
+    fn step(&mut self, forward: bool, out: &mut impl Write) -> io::Result<()> {
+        let Some(topic) = self.topic else {
+            return writeln!(out, "`cd` into a topic first (try `topics`)");
+        };
+        let count = self.index.functions(topic.name).count();
+
+        let position = match (self.position, forward) {
+            (None, true) if count > 0 => 0,
+            (Some(p), true) if p + 1 < count => p + 1,
+            (Some(p), false) if p > 0 => p - 1,
+            (_, true) => match next_topic(topic) {
+                Some(next) => {
+                    writeln!(out, "==> end of {}, moving on to {}", topic.name, next.name)?;
+                    self.enter(next);
+                    return self.step(true, out);
+                }
+                None => return writeln!(out, "that was the last topic, well done!"),
+            },
+            (_, false) => return writeln!(out, "already at the first function of {}", topic.name),
+        };
+
+        self.position = Some(position);
+        self.show(out)
+    }
+
+    pub fn complete(&self, before: &str) -> Vec<String> {
+        let words: Vec<&str> = before.split(' ').collect();
+        let word = *words.last().unwrap_or(&"");
+
+        let candidates: Vec<String> = match words.as_slice() {
+            [_] => COMMANDS.iter().map(|c| c.to_string()).collect(),
+            ["cd", _] => registry::topics()
+                .iter()
+                .map(|t| t.name.to_string())
+                .collect(),
+            ["show" | "run", _] => match self.topic {
+                Some(topic) => {
+                    let mut names: Vec<String> = self
+                        .index
+                        .functions(topic.name)
+                        .map(|f| f.name.to_string())
+                        .chain(topic.examples.iter().map(|e| e.id.to_string()))
+                        .collect();
+                    names.sort();
+                    names.dedup();
+                    names
+                }
+                None => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+
+        candidates
+            .into_iter()
+            .filter(|candidate| candidate.starts_with(word))
+            .collect()
+    }
+}
+
+fn next_topic(topic: &Topic) -> Option<&'static Topic> {
+    let order = curriculum::curriculum().ok()?;
+    let position = order.iter().position(|t| t.name == topic.name)?;
+    order.get(position + 1).copied()
+}
+
+// A panicking example is reported, it doesn't end the session.
+fn run(example: &'static Example, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "==> {} ({})", example.path(), example.title)?;
+    out.flush()?;
+    if panic::catch_unwind(example.run).is_err() {
+        writeln!(out, "==> {} panicked", example.path())?;
+    }
+    Ok(())
+}
+
+pub fn run_shell() -> io::Result<()> {
+    let mut shell = Shell::new();
+    let mut editor = Editor::new();
+    let mut stdout = io::stdout();
+
+    println!("Lessons shell, `help` lists the commands.");
+    loop {
+        let prompt = shell.prompt();
+        let Some(line) = editor.read_line(&prompt, |before| shell.complete(before))? else {
+            return Ok(());
+        };
+        if let Flow::Exit = shell.execute(&line, &mut stdout)? {
+            return Ok(());
+        }
+    }
+}
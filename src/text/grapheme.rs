@@ -0,0 +1,209 @@
+// # Graphemes
+//
+// A grapheme cluster is what a reader takes for one character, even when it is made of several chars:
+//
+// - `é` written as `e` + U+0301 (a combining accent)
+// - `👍🏽`, a thumb and a skin tone modifier
+// - `👩‍🚀`, a woman and a rocket joined by a zero width joiner (U+200D)
+// - `🇫🇷`, a flag made of two regional indicators
+// - `क्षि`, a Devanagari conjunct: two consonants joined by a virama, and a vowel sign
+// - `\r\n`
+//
+// `.chars()` splits all of them. The segmenter follows the extended grapheme cluster rules of Unicode's
+// text segmentation (UAX #29, https://www.unicode.org/reports/tr29/), rule by rule (GB3 to GB999).
+//
+// The rules work on properties of the chars, looked up in tables that `build.rs` generates from the
+// Unicode data files in `data/ucd/`. `tests/grapheme_break.rs` checks every case of the conformance test
+// that comes with them, `GraphemeBreakTest.txt`.
+
+use super::lookup;
+
+// Grapheme_Cluster_Break: how a char behaves at the edges of a cluster.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Property {
+    Other,
+    Cr,
+    Lf,
+    Control,
+    Extend,
+    Zwj,
+    RegionalIndicator,
+    Prepend,
+    SpacingMark,
+    // Hangul: leading consonant, vowel, trailing consonant, and the precomposed syllables.
+    L,
+    V,
+    T,
+    Lv,
+    Lvt,
+}
+
+// Indic_Conjunct_Break: the consonants of the scripts whose viramas (the linkers) join them into conjuncts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Conjunct {
+    Linker,
+    Consonant,
+    Extend,
+}
+
+include!(concat!(env!("OUT_DIR"), "/grapheme_tables.rs"));
+
+pub fn property(c: char) -> Property {
+    lookup(GRAPHEME_BREAK, c, |&(start, end, _)| (start, end))
+        .map_or(Property::Other, |entry| entry.2)
+}
+
+pub fn is_extended_pictographic(c: char) -> bool {
+    lookup(EXTENDED_PICTOGRAPHIC, c, |&range| range).is_some()
+}
+
+fn conjunct(c: char) -> Option<Conjunct> {
+    lookup(INDIC_CONJUNCT_BREAK, c, |&(start, end, _)| (start, end)).map(|entry| entry.2)
+}
+
+// GB11 and GB9c look further back than the previous char, at the end of the cluster so far.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Emoji {
+    None,
+    // An Extended_Pictographic, then Extends.
+    Pictographic,
+    // The same, then a ZWJ.
+    Joined,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Conjunction {
+    None,
+    // An InCB Consonant, then InCB Extends.
+    Consonant,
+    // The same, with at least one InCB Linker among the Extends.
+    Linked,
+}
+
+struct State {
+    previous: Property,
+    emoji: Emoji,
+    conjunction: Conjunction,
+    // How many regional indicators in a row end the cluster.
+    regional_indicators: usize,
+}
+
+impl State {
+    fn new(first: char) -> Self {
+        let mut state = Self {
+            previous: Property::Other,
+            emoji: Emoji::None,
+            conjunction: Conjunction::None,
+            regional_indicators: 0,
+        };
+        state.push(first);
+        state
+    }
+
+    // Whether the cluster ends before `next`.
+    fn breaks_before(&self, next: char) -> bool {
+        use Property::*;
+
+        match (self.previous, property(next)) {
+            // GB3, GB4, GB5: line breaks are clusters of their own.
+            (Cr, Lf) => false,
+            (Cr | Lf | Control, _) | (_, Cr | Lf | Control) => true,
+            // GB6, GB7, GB8: Hangul syllables.
+            (L, L | V | Lv | Lvt) | (Lv | V, V | T) | (Lvt | T, T) => false,
+            // GB9, GB9a, GB9b: marks belong to what comes before them, prepended marks to what comes after.
+            (_, Extend | Zwj | SpacingMark) | (Prepend, _) => false,
+            // GB12, GB13: flags are pairs of regional indicators.
+            (RegionalIndicator, RegionalIndicator) => self.regional_indicators.is_multiple_of(2),
+            _ => {
+                // GB9c: consonants joined by a linker.
+                let conjunct = self.conjunction == Conjunction::Linked
+                    && conjunct(next) == Some(Conjunct::Consonant);
+                // GB11: emoji joined by a ZWJ.
+                let emoji = self.emoji == Emoji::Joined && is_extended_pictographic(next);
+                // GB999
+                !(conjunct || emoji)
+            }
+        }
+    }
+
+    fn push(&mut self, c: char) {
+        let property = property(c);
+
+        self.emoji = match (self.emoji, property) {
+            _ if is_extended_pictographic(c) => Emoji::Pictographic,
+            (Emoji::Pictographic, Property::Extend) => Emoji::Pictographic,
+            (Emoji::Pictographic, Property::Zwj) => Emoji::Joined,
+            _ => Emoji::None,
+        };
+        self.conjunction = match (self.conjunction, conjunct(c)) {
+            (_, Some(Conjunct::Consonant)) => Conjunction::Consonant,
+            (Conjunction::None, _) => Conjunction::None,
+            (_, Some(Conjunct::Linker)) => Conjunction::Linked,
+            (conjunction, Some(Conjunct::Extend)) => conjunction,
+            (_, None) => Conjunction::None,
+        };
+        self.regional_indicators = if property == Property::RegionalIndicator {
+            self.regional_indicators + 1
+        } else {
+            0
+        };
+        self.previous = property;
+    }
+}
+
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+pub fn graphemes(s: &str) -> Graphemes<'_> {
+    Graphemes { rest: s }
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.rest.char_indices();
+        let (_, first) = chars.next()?;
+
+        let mut state = State::new(first);
+        let mut end = self.rest.len();
+        for (byte, c) in chars {
+            if state.breaks_before(c) {
+                end = byte;
+                break;
+            }
+            state.push(c);
+        }
+
+        let (cluster, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(cluster)
+    }
+}
+
+pub fn grapheme_count(s: &str) -> usize {
+    graphemes(s).count()
+}
+
+// The byte offset where the cluster `index` starts, the cluster count being the end of the string.
+pub fn grapheme_to_byte(s: &str, index: usize) -> Option<usize> {
+    graphemes(s)
+        .scan(0, |byte, cluster| {
+            let start = *byte;
+            *byte += cluster.len();
+            Some(start)
+        })
+        .chain([s.len()])
+        .nth(index)
+}
+
+// The grapheme clusters `start..end` of `s`.
+pub fn grapheme_slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    if start > end {
+        return None;
+    }
+    let from = grapheme_to_byte(s, start)?;
+    let to = grapheme_to_byte(&s[from..], end - start)?;
+    Some(&s[from..from + to])
+}
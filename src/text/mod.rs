@@ -0,0 +1,73 @@
+// # Text
+//
+// A `&str` is indexed by bytes: `&s[4..8]` is 4 bytes, which may be one character (`🚀`), four (`"The "`)
+// or half of one, and slicing inside of a character panics. This module works with the other units a
+// string can be counted in:
+//
+// - bytes      -> `substring`, which returns `None` instead of panicking off a char boundary
+// - chars      -> `char_slice`, `char_to_byte` and `byte_to_char`
+// - graphemes  -> `grapheme::graphemes` and `grapheme_slice`: what a reader sees as one character, like
+//                 `é` written as `e` + U+0301, or a flag made of two regional indicators
+// - columns    -> `width::width`, the number of terminal columns: `🚀` and `ぁ` take two, U+0301 none
+//
+// Everything is computed on the fly from the UTF-8 bytes, nothing is cached: every char index costs a walk
+// from the start of the string. A text that is long and edited everywhere is better kept in a `Rope`.
+
+pub mod grapheme;
+pub mod rope;
+pub mod width;
+
+pub use grapheme::{grapheme_slice, graphemes};
+pub use rope::Rope;
+pub use width::width;
+
+use std::cmp::Ordering;
+
+// The bytes `start..end` of `s`, if both ends fall between two chars.
+pub fn substring(s: &str, start: usize, end: usize) -> Option<&str> {
+    s.get(start..end)
+}
+
+// The byte offset where the char `index` starts. The char count itself is accepted, as the end of the
+// string, the way `s.len()` is a valid end of a byte range.
+pub fn char_to_byte(s: &str, index: usize) -> Option<usize> {
+    s.char_indices()
+        .map(|(byte, _)| byte)
+        .chain([s.len()])
+        .nth(index)
+}
+
+// The index of the char that starts at `byte`, `None` inside of a char.
+pub fn byte_to_char(s: &str, byte: usize) -> Option<usize> {
+    if !s.is_char_boundary(byte) {
+        return None;
+    }
+    Some(s[..byte].chars().count())
+}
+
+// The chars `start..end` of `s`.
+pub fn char_slice(s: &str, start: usize, end: usize) -> Option<&str> {
+    if start > end {
+        return None;
+    }
+    let from = char_to_byte(s, start)?;
+    let to = char_to_byte(&s[from..], end - start)?;
+    Some(&s[from..from + to])
+}
+
+// The entry of a sorted table of ranges that holds `c`.
+fn lookup<T>(table: &[T], c: char, range: impl Fn(&T) -> (char, char)) -> Option<&T> {
+    table
+        .binary_search_by(|entry| {
+            let (start, end) = range(entry);
+            if end < c {
+                Ordering::Less
+            } else if start > c {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .ok()
+        .map(|index| &table[index])
+}
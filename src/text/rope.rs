@@ -0,0 +1,458 @@
+// # Rope
+//
+// A `String` is one block of bytes (ptr, len, capacity, see the `string` lesson): inserting or removing in
+// the middle moves every byte after that point. For a text that is edited everywhere, like the buffer of
+// an editor, each edit costs O(n).
+//
+// A rope cuts the text into chunks of at most `MAX_CHUNK` bytes, kept in a balanced binary tree in text
+// order. Every node knows the size of its subtree (bytes, chars, newlines), so finding the char `i` or the
+// line `l` walks down one branch, and an edit touches one chunk and its ancestors: O(log n).
+//
+// The tree is a treap: each node also gets a random priority and parents have higher priorities than their
+// children, which keeps it balanced on average without any rotation. Everything is built on two operations:
+//
+// - `split(tree, i)` cuts a tree in two at the char `i`
+// - `merge(left, right)` joins two trees, all of `left` coming first
+//
+// Inserting is splitting at the position and merging the new text in the middle, removing is splitting
+// twice and dropping the middle. An edit that fits in its chunk is made in place.
+//
+// Positions are char indices. As with `String`, a position out of the text panics.
+//
+// `cargo bench --bench rope` compares it with `String::insert_str`.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::ops::Range;
+
+const MAX_CHUNK: usize = 1024;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Summary {
+    bytes: usize,
+    chars: usize,
+    newlines: usize,
+}
+
+impl Summary {
+    fn of(s: &str) -> Self {
+        Self {
+            bytes: s.len(),
+            chars: s.chars().count(),
+            newlines: s.bytes().filter(|&b| b == b'\n').count(),
+        }
+    }
+
+    fn add(self, other: Summary) -> Summary {
+        Summary {
+            bytes: self.bytes + other.bytes,
+            chars: self.chars + other.chars,
+            newlines: self.newlines + other.newlines,
+        }
+    }
+}
+
+type Tree = Option<Box<Node>>;
+
+struct Node {
+    chunk: String,
+    priority: u64,
+    // The chunk's own summary, and the one of the whole subtree.
+    own: Summary,
+    total: Summary,
+    left: Tree,
+    right: Tree,
+}
+
+impl Node {
+    fn update(&mut self) {
+        self.total = total(&self.left).add(self.own).add(total(&self.right));
+    }
+}
+
+fn total(tree: &Tree) -> Summary {
+    tree.as_ref().map_or(Summary::default(), |node| node.total)
+}
+
+fn merge(left: Tree, right: Tree) -> Tree {
+    match (left, right) {
+        (None, tree) | (tree, None) => tree,
+        (Some(mut left), Some(mut right)) => {
+            if left.priority > right.priority {
+                left.right = merge(left.right.take(), Some(right));
+                left.update();
+                Some(left)
+            } else {
+                right.left = merge(Some(left), right.left.take());
+                right.update();
+                Some(right)
+            }
+        }
+    }
+}
+
+// The byte offset of the char `index` of a chunk, which has it.
+fn byte_of(chunk: &str, index: usize) -> usize {
+    super::char_to_byte(chunk, index).expect("char index inside of the chunk")
+}
+
+pub struct Rope {
+    root: Tree,
+    // The state of the generator of priorities (xorshift).
+    seed: u64,
+}
+
+impl Rope {
+    pub fn new() -> Self {
+        Self {
+            root: None,
+            seed: 0x2545_F491_4F6C_DD1D,
+        }
+    }
+
+    pub fn len_bytes(&self) -> usize {
+        total(&self.root).bytes
+    }
+
+    pub fn len_chars(&self) -> usize {
+        total(&self.root).chars
+    }
+
+    // An empty rope has one (empty) line, and a text ending with '\n' has an empty last line.
+    pub fn len_lines(&self) -> usize {
+        total(&self.root).newlines + 1
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len_bytes() == 0
+    }
+
+    pub fn insert(&mut self, at: usize, text: &str) {
+        self.check_position(at);
+        if text.is_empty() {
+            return;
+        }
+        if text.len() <= MAX_CHUNK && insert_in_place(&mut self.root, at, text) {
+            return;
+        }
+
+        let root = self.root.take();
+        let (left, right) = self.split(root, at);
+        let middle = self.build(text);
+        self.root = merge(merge(left, middle), right);
+    }
+
+    pub fn remove(&mut self, range: Range<usize>) {
+        self.check_range(&range);
+        if range.is_empty() || remove_in_place(&mut self.root, range.start, range.end) {
+            return;
+        }
+
+        let root = self.root.take();
+        let (left, rest) = self.split(root, range.start);
+        let (_, right) = self.split(rest, range.len());
+        self.root = merge(left, right);
+    }
+
+    pub fn char_to_byte(&self, at: usize) -> usize {
+        self.check_position(at);
+        match self.find(at, |summary| summary.chars) {
+            Some((chunk, before)) => before.bytes + byte_of(chunk, at - before.chars),
+            None => self.len_bytes(),
+        }
+    }
+
+    // The line of the char `at`, counted from 0.
+    pub fn char_to_line(&self, at: usize) -> usize {
+        self.check_position(at);
+        match self.find(at, |summary| summary.chars) {
+            Some((chunk, before)) => {
+                let offset = byte_of(chunk, at - before.chars);
+                before.newlines + Summary::of(&chunk[..offset]).newlines
+            }
+            None => total(&self.root).newlines,
+        }
+    }
+
+    // The char where the line `line` starts: after the `line`-th newline.
+    pub fn line_to_char(&self, line: usize) -> usize {
+        assert!(
+            line < self.len_lines(),
+            "line {} out of a rope of {} lines",
+            line,
+            self.len_lines()
+        );
+        if line == 0 {
+            return 0;
+        }
+        let (chunk, before) = self
+            .find(line - 1, |summary| summary.newlines)
+            .expect("the rope has this many newlines");
+        let newline = chunk
+            .match_indices('\n')
+            .nth(line - 1 - before.newlines)
+            .map(|(byte, _)| byte)
+            .expect("the chunk has this newline");
+        before.chars + chunk[..newline].chars().count() + 1
+    }
+
+    // The line `line`, with its '\n'.
+    pub fn line(&self, line: usize) -> Cow<'_, str> {
+        let start = self.line_to_char(line);
+        let end = if line + 1 < self.len_lines() {
+            self.line_to_char(line + 1)
+        } else {
+            self.len_chars()
+        };
+        self.slice(start..end)
+    }
+
+    // The chars `range`: borrowed from the rope when they are all in one chunk, copied otherwise.
+    pub fn slice(&self, range: Range<usize>) -> Cow<'_, str> {
+        self.check_range(&range);
+        let bytes = self.char_to_byte(range.start)..self.char_to_byte(range.end);
+        if let Some((chunk, before)) = self.find(range.start, |summary| summary.chars) {
+            let start = bytes.start - before.bytes;
+            if let Some(slice) = chunk.get(start..bytes.end - before.bytes) {
+                return Cow::Borrowed(slice);
+            }
+        }
+
+        let mut text = String::with_capacity(bytes.len());
+        collect(&self.root, bytes, &mut text);
+        Cow::Owned(text)
+    }
+
+    pub fn chunks(&self) -> Chunks<'_> {
+        let mut chunks = Chunks { stack: Vec::new() };
+        chunks.push_left(&self.root);
+        chunks
+    }
+
+    // The chunk holding the unit `index` (a char or a newline, as `key` counts them), with the summary of
+    // everything before that chunk. `None` past the end.
+    fn find(&self, mut index: usize, key: impl Fn(&Summary) -> usize) -> Option<(&str, Summary)> {
+        let mut tree = &self.root;
+        let mut before = Summary::default();
+        while let Some(node) = tree {
+            let left = total(&node.left);
+            if index < key(&left) {
+                tree = &node.left;
+            } else if index < key(&left) + key(&node.own) {
+                return Some((&node.chunk, before.add(left)));
+            } else {
+                index -= key(&left) + key(&node.own);
+                before = before.add(left).add(node.own);
+                tree = &node.right;
+            }
+        }
+        None
+    }
+
+    // The chars `..at` and `at..` of `tree`.
+    fn split(&mut self, tree: Tree, at: usize) -> (Tree, Tree) {
+        let Some(mut node) = tree else {
+            return (None, None);
+        };
+        let left = total(&node.left).chars;
+        if at <= left {
+            let (before, after) = self.split(node.left.take(), at);
+            node.left = after;
+            node.update();
+            (before, Some(node))
+        } else if at >= left + node.own.chars {
+            let (before, after) = self.split(node.right.take(), at - left - node.own.chars);
+            node.right = before;
+            node.update();
+            (Some(node), after)
+        } else {
+            // The position is inside of the chunk: its tail goes to a new node.
+            let tail = node.chunk.split_off(byte_of(&node.chunk, at - left));
+            node.own = Summary::of(&node.chunk);
+            let after = node.right.take();
+            node.update();
+            let tail = self.build(&tail);
+            (Some(node), merge(tail, after))
+        }
+    }
+
+    // A tree of the chunks of `text`.
+    fn build(&mut self, mut text: &str) -> Tree {
+        let mut tree = None;
+        while !text.is_empty() {
+            let mut end = text.len().min(MAX_CHUNK);
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            let (chunk, rest) = text.split_at(end);
+            let leaf = self.leaf(chunk.to_string());
+            tree = merge(tree, Some(leaf));
+            text = rest;
+        }
+        tree
+    }
+
+    fn leaf(&mut self, chunk: String) -> Box<Node> {
+        self.seed ^= self.seed << 13;
+        self.seed ^= self.seed >> 7;
+        self.seed ^= self.seed << 17;
+        let own = Summary::of(&chunk);
+        Box::new(Node {
+            chunk,
+            priority: self.seed,
+            own,
+            total: own,
+            left: None,
+            right: None,
+        })
+    }
+
+    fn check_position(&self, at: usize) {
+        assert!(
+            at <= self.len_chars(),
+            "char index {} out of a rope of {} chars",
+            at,
+            self.len_chars()
+        );
+    }
+
+    fn check_range(&self, range: &Range<usize>) {
+        assert!(
+            range.start <= range.end,
+            "range {:?} starts after its end",
+            range
+        );
+        self.check_position(range.end);
+    }
+}
+
+// Inserts into the chunk that holds the position, if the text fits in it.
+fn insert_in_place(tree: &mut Tree, at: usize, text: &str) -> bool {
+    let Some(node) = tree else {
+        return false;
+    };
+    let left = total(&node.left).chars;
+    let inserted = if at < left {
+        insert_in_place(&mut node.left, at, text)
+    } else if at <= left + node.own.chars {
+        if node.own.bytes + text.len() > MAX_CHUNK {
+            return false;
+        }
+        let byte = byte_of(&node.chunk, at - left);
+        node.chunk.insert_str(byte, text);
+        node.own = node.own.add(Summary::of(text));
+        true
+    } else {
+        insert_in_place(&mut node.right, at - left - node.own.chars, text)
+    };
+    if inserted {
+        node.update();
+    }
+    inserted
+}
+
+// Removes from the chunk that holds the whole range, if there is one. A chunk left empty is dropped.
+fn remove_in_place(tree: &mut Tree, start: usize, end: usize) -> bool {
+    let Some(node) = tree else {
+        return false;
+    };
+    let left = total(&node.left).chars;
+    let own = node.own.chars;
+    let removed = if end <= left {
+        remove_in_place(&mut node.left, start, end)
+    } else if start >= left + own {
+        remove_in_place(&mut node.right, start - left - own, end - left - own)
+    } else if start >= left && end <= left + own {
+        let bytes = byte_of(&node.chunk, start - left)..byte_of(&node.chunk, end - left);
+        node.chunk.replace_range(bytes, "");
+        node.own = Summary::of(&node.chunk);
+        if node.chunk.is_empty() {
+            *tree = merge(node.left.take(), node.right.take());
+            return true;
+        }
+        true
+    } else {
+        false
+    };
+    if removed {
+        node.update();
+    }
+    removed
+}
+
+// Appends the bytes `range` of `tree` to `text`, visiting only the subtrees they are in.
+fn collect(tree: &Tree, range: Range<usize>, text: &mut String) {
+    let Some(node) = tree else {
+        return;
+    };
+    if range.is_empty() {
+        return;
+    }
+    let left = total(&node.left).bytes;
+    let own = left..left + node.own.bytes;
+    if range.start < left {
+        collect(&node.left, range.start..range.end.min(left), text);
+    }
+    if range.start < own.end && range.end > own.start {
+        let start = range.start.max(own.start) - left;
+        let end = range.end.min(own.end) - left;
+        text.push_str(&node.chunk[start..end]);
+    }
+    if range.end > own.end {
+        let start = range.start.max(own.end) - own.end;
+        collect(&node.right, start..range.end - own.end, text);
+    }
+}
+
+impl Default for Rope {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<&str> for Rope {
+    fn from(text: &str) -> Self {
+        let mut rope = Rope::new();
+        rope.root = rope.build(text);
+        rope
+    }
+}
+
+impl fmt::Display for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for chunk in self.chunks() {
+            f.write_str(chunk)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Rope {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_list().entries(self.chunks()).finish()
+    }
+}
+
+// The chunks in text order: the nodes of the tree, in order.
+pub struct Chunks<'a> {
+    stack: Vec<&'a Node>,
+}
+
+impl<'a> Chunks<'a> {
+    fn push_left(&mut self, mut tree: &'a Tree) {
+        while let Some(node) = tree {
+            self.stack.push(node);
+            tree = &node.left;
+        }
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<&'a str> {
+        let node = self.stack.pop()?;
+        self.push_left(&node.right);
+        Some(&node.chunk)
+    }
+}
@@ -0,0 +1,49 @@
+// # Display Width
+//
+// A terminal draws most characters in one column, but East Asian ideographs, kana, Hangul and most emoji
+// take two (`ぁ`, `🚀`), and combining marks take none: they are drawn over the previous character.
+// Padding a table with `{:<10}` counts chars, so a column holding `🚀` comes out one column too wide.
+//
+// The width of a string is the sum of the widths of its grapheme clusters, and a cluster is as wide as its
+// first char: `👩‍🚀` is three chars but one picture, two columns wide. A text-style symbol followed by
+// the emoji variation selector (U+FE0F, as in `❤️`) is drawn as an emoji, two columns wide.
+//
+// The tables come from the Unicode data in `data/ucd/`: a char is two columns wide if its East_Asian_Width
+// is Wide or Fullwidth, and none if its general category is a nonspacing or enclosing mark (Mn, Me) or a
+// format char (Cf). Spacing marks (Mc), like the Devanagari vowel sign `ा`, take a column of their own.
+
+use super::grapheme;
+
+// `ZERO`: marks drawn over the previous char (variation selectors among them) and the invisible formatting
+// chars. `WIDE`: the chars two columns wide. Both are generated by `build.rs`.
+include!(concat!(env!("OUT_DIR"), "/width_tables.rs"));
+
+fn contains(ranges: &[(char, char)], c: char) -> bool {
+    super::lookup(ranges, c, |&range| range).is_some()
+}
+
+// The width of one char on its own.
+pub fn char_width(c: char) -> usize {
+    if c.is_control() || contains(ZERO, c) {
+        0
+    } else if contains(WIDE, c) {
+        2
+    } else {
+        1
+    }
+}
+
+pub fn cluster_width(cluster: &str) -> usize {
+    let mut chars = cluster.chars();
+    let Some(first) = chars.next() else {
+        return 0;
+    };
+    if chars.any(|c| c == '\u{FE0F}') && grapheme::is_extended_pictographic(first) {
+        return 2;
+    }
+    char_width(first)
+}
+
+pub fn width(s: &str) -> usize {
+    grapheme::graphemes(s).map(cluster_width).sum()
+}
@@ -18,3 +18,7 @@
 // - Dangling pointers: A pointer that references a memory location that has already been freed.
 // - Double free: Attempting to free the same memory location more than once.
 // - Memory leaks: Memory that is allocated but never freed.
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
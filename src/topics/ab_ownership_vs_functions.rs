@@ -39,3 +39,20 @@ fn takes_and_gives_back(a_string: String) -> String {
     // a_string comes into scope
     a_string // a_string is returned and moves out to the calling function
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "ab_ownership_vs_functions",
+        "ownership_vs_functions",
+        "Passing values into functions moves or copies them",
+        ownership_vs_functions,
+    ),
+    Example::new(
+        "ab_ownership_vs_functions",
+        "ownership_vs_functions_2",
+        "Returning values gives ownership back",
+        ownership_vs_functions_2,
+    ),
+];
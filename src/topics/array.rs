@@ -2,3 +2,7 @@
 //- Fixed-size collection of elements of the same data type stored as coniguous block in stack memory.
 //- Signature of array is [T, Length], which indicates that the length is fixed at compile time.
 //- Arrays can neither grow or shrink, they must retain their size.
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
@@ -40,3 +40,20 @@ impl TrafficLight {
         &self.color
     }
 }
+
+pub fn traffic_light_example() {
+    let light = TrafficLight::new();
+    println!(
+        "The initial state of the traffic light is: {}",
+        light.get_state()
+    );
+}
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "associated_function",
+    "traffic_light_example",
+    "Calling the `TrafficLight::new` associated function",
+    traffic_light_example,
+)];
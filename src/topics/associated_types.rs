@@ -20,3 +20,12 @@ impl MyTrait for MyStruct {
         return 42;
     }
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "associated_types",
+    "get_my_type",
+    "Trait with an associated type",
+    || println!("MyType: {}", MyStruct {}.get_my_type()),
+)];
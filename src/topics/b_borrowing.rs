@@ -43,3 +43,32 @@ pub fn _borrowing() {
     let r3 = &mut s; // mutable borrow
     println!("{}", r3); // no error: r1 and r2 are no longer used
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "b_borrowing",
+        "_test_borrowing",
+        "Borrowing a String to calculate its length",
+        _test_borrowing,
+    ),
+    Example::new(
+        "b_borrowing",
+        "_viloate_borrowing_1",
+        "Mixing immutable and mutable borrows",
+        _viloate_borrowing_1,
+    ),
+    Example::new(
+        "b_borrowing",
+        "_violate_borrowing_2",
+        "Two mutable borrows at the same time",
+        _violate_borrowing_2,
+    ),
+    Example::new(
+        "b_borrowing",
+        "_borrowing",
+        "Immutable borrows end before a mutable one",
+        _borrowing,
+    ),
+];
@@ -33,3 +33,44 @@
 //
 //     let boxed = Box::new(42);               // Heap-allocated value
 //     let animal: Box<dyn Animal> = Box::new(Dog {});
+
+// Box::new asks the allocator for room for the value, and the Box frees it when it goes out of scope.
+// A reference to a value on the stack allocates nothing.
+// `run --stats box_1` (built with the `alloc-stats` feature) shows the difference.
+pub fn example_box_new() {
+    let boxed = Box::new([0u64; 4]);
+
+    println!(
+        "Box::new -> {} bytes on the heap, the Box itself is {} bytes",
+        std::mem::size_of_val(&*boxed),
+        std::mem::size_of_val(&boxed)
+    );
+}
+
+pub fn example_reference() {
+    let value = [0u64; 4];
+    let reference = &value;
+
+    println!(
+        "& -> {} bytes on the stack, the reference itself is {} bytes",
+        std::mem::size_of_val(reference),
+        std::mem::size_of_val(&reference)
+    );
+}
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "box_1",
+        "example_box_new",
+        "`Box::new` allocates on the heap",
+        example_box_new,
+    ),
+    Example::new(
+        "box_1",
+        "example_reference",
+        "A reference doesn't allocate",
+        example_reference,
+    ),
+];
@@ -74,3 +74,60 @@ pub fn string_iterator() {
         println!("{}", c);
     }
 }
+
+// A char is a Unicode code point, not always what a reader sees as one character: `é` can be written as
+// `e` + a combining accent, a flag is two chars, and `👩‍🚀` is three (a woman, a joiner and a rocket).
+// `text::graphemes` iterates over the grapheme clusters: the "user-perceived" characters.
+use crate::text;
+pub fn grapheme_iterator() {
+    let s = "cafe\u{301} 🇫🇷 👩\u{200D}🚀";
+
+    println!(
+        "{} chars: {:?}",
+        s.chars().count(),
+        s.chars().collect::<Vec<_>>()
+    );
+
+    let graphemes: Vec<&str> = text::graphemes(s).collect();
+    println!("{} graphemes: {:?}", graphemes.len(), graphemes);
+}
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "c_string_vs_string_slice",
+        "concatenate_strings",
+        "Concatenating a String with a &str",
+        concatenate_strings,
+    ),
+    Example::new(
+        "c_string_vs_string_slice",
+        "string_index",
+        "Slicing a String by byte range",
+        string_index,
+    ),
+    Example::new(
+        "c_string_vs_string_slice",
+        "string_iterator",
+        "Iterating over the chars of a String",
+        string_iterator,
+    ),
+    Example::new(
+        "c_string_vs_string_slice",
+        "grapheme_iterator",
+        "Iterating over the grapheme clusters of a string",
+        grapheme_iterator,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    question!("c_string_vs_string_slice", { let s1 = String::from("hi, #$"); } => &s1[3..5]),
+    question!("c_string_vs_string_slice", {
+        let s1 = String::from("hello");
+        let s2 = String::from("world");
+        let s3 = s1 + &s2;
+    } => s3),
+];
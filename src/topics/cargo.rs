@@ -30,3 +30,7 @@
 // which has the name of the module and a mod.rs file inside it.
 
 // See rust-trainning-cargo
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
@@ -301,3 +301,85 @@ pub fn _factory(x: i32) -> Box<dyn Fn(i32) -> i32> {
         Box::new(move |x| x + num)
     }
 }
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "closures",
+        "_example_closures_01",
+        "Closure capturing `x` from its scope",
+        _example_closures_01,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_02",
+        "Closures vs functions with inferred types",
+        _example_closures_02,
+    ),
+    Example::new(
+        "closures",
+        "_example_closure_capturing_01",
+        "Capturing by immutable reference",
+        _example_closure_capturing_01,
+    ),
+    Example::new(
+        "closures",
+        "_example_closure_capturing_02",
+        "`move` closure copying an `i32`",
+        _example_closure_capturing_02,
+    ),
+    Example::new(
+        "closures",
+        "_example_closure_capturing_03",
+        "Calling a closure that borrows a Box",
+        _example_closure_capturing_03,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_03",
+        "Closure types are inferred from the first call",
+        _example_closures_03,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_04",
+        "Passing a closure to a `Fn` bound",
+        _example_closures_04,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_05",
+        "Passing a closure to a `FnMut` bound",
+        _example_closures_05,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_06",
+        "How the compiler picks Fn, FnMut or FnOnce",
+        _example_closures_06,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_07",
+        "Returning a captured value requires `FnOnce`",
+        _example_closures_07,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_08",
+        "Functions can be passed where closures are expected",
+        _example_closures_08,
+    ),
+    Example::new(
+        "closures",
+        "_example_closures_09",
+        "Returning closures with `impl Fn` and `Box<dyn Fn>`",
+        _example_closures_09,
+    ),
+];
+
+pub const QUIZ: &[Question] =
+    &[question!("closures", { let x = 1; let closure = |val| val + x; } => closure(2))];
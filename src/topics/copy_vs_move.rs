@@ -15,3 +15,12 @@ pub fn copy_vs_move() {
                  // println!("{}", s1); // this will throw an error, because s1 is no longer the owner of the string
     println!("s2: {}", s2); // this will work, because s2 is the owner of the string
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "copy_vs_move",
+    "copy_vs_move",
+    "Copying an i32 vs moving a String",
+    copy_vs_move,
+)];
@@ -29,3 +29,37 @@ pub fn _example_slice_03() {
 
     assert_eq!(slice, &[2, 3, 4]);
 }
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "d_slice",
+        "_example_slice_01",
+        "Slicing an array",
+        _example_slice_01,
+    ),
+    Example::new(
+        "d_slice",
+        "_example_slice_02",
+        "Size of a slice reference",
+        _example_slice_02,
+    ),
+    Example::new(
+        "d_slice",
+        "_example_slice_03",
+        "Slicing a range of an array",
+        _example_slice_03,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    question!("d_slice", { let a = [1, 2, 3, 4, 5]; } => &a[1..3]),
+    question!("d_slice", {
+        let arr = ['₯', '₳', '￥'];
+        let slice = &arr[..2];
+    } => std::mem::size_of_val(&slice)),
+    question!("d_slice", { let arr = [1, 2, 3, 4, 5]; } => &arr[1..4]),
+];
@@ -134,3 +134,56 @@ pub fn example_display_02() {
     println!("{}", v);
     assert_eq!(format!("{}", v), "[0: 1, 1: 2, 2: 3]");
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "debug_display",
+        "example_format_01",
+        "Building a String with `format!`",
+        example_format_01,
+    ),
+    Example::new(
+        "debug_display",
+        "example_print_01",
+        "`print!` vs `println!`",
+        example_print_01,
+    ),
+    Example::new(
+        "debug_display",
+        "example_debug_01",
+        "Deriving Debug",
+        example_debug_01,
+    ),
+    Example::new(
+        "debug_display",
+        "example_debug_02",
+        "Printing a struct with `{:?}`",
+        example_debug_02,
+    ),
+    Example::new(
+        "debug_display",
+        "example_debug_03",
+        "Pretty printing with `{:#?}`",
+        example_debug_03,
+    ),
+    Example::new(
+        "debug_display",
+        "example_debug_04",
+        "Implementing Debug manually",
+        example_debug_04,
+    ),
+    Example::new(
+        "debug_display",
+        "example_display_01",
+        "Implementing Display and Debug for a point",
+        example_display_01,
+    ),
+    Example::new(
+        "debug_display",
+        "example_display_02",
+        "Implementing Display for a list",
+        example_display_02,
+    ),
+];
@@ -8,3 +8,12 @@ pub fn deep_copy() {
 
     println!("s1: {}, s2: {}", s1, s2); // this will work, because s1 and s2 are both owners of their own data
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "deep_copy",
+    "deep_copy",
+    "Cloning heap data",
+    deep_copy,
+)];
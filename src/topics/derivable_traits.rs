@@ -5,3 +5,7 @@
 // - Clone: enables type to be duplicated with "clone()" method
 // - Copy: enables type to be copied implicity, without requiring "clone()"
 // - PartialEq: enables comparison
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
@@ -31,7 +31,7 @@
 // We have a function that takes random number and returns a type that implements the Animal trait (either Dog or Cat).
 // Compiler can not know size at compile time, as one type might be bigger than the other
 // The noise() method gets called on returned type that implements Animal trait, again compiler can not know this, this resolves at runtime.
-trait Animal {
+pub trait Animal {
     fn noise(&self);
 }
 
@@ -57,3 +57,12 @@ pub fn random_animal(random_number: u8) -> Box<dyn Animal> {
         Box::new(Dog {})
     }
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "dynamic_dispatch",
+    "random_animal",
+    "Returning a `Box<dyn Animal>` chosen at runtime",
+    || random_animal(3).noise(),
+)];
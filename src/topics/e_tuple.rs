@@ -25,6 +25,7 @@ pub fn long_tuples() {
         1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
     );
     // println!("too long tuple {:?}", too_long_tuple); // error: the trait `std::fmt::Debug` is not implemented for `T`
+    // See tests/compile_fail/e_tuple_long_tuple_debug.rs
 }
 
 pub fn destructuring_tuples() {
@@ -65,3 +66,49 @@ pub fn tuples_as_function_arguments() {
     assert_eq!(x, 5);
     assert_eq!(y, 6);
 }
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new("e_tuple", "tuples_01", "Declaring tuples", tuples_01),
+    Example::new(
+        "e_tuple",
+        "tuples_02",
+        "Accessing tuple fields by index",
+        tuples_02,
+    ),
+    Example::new(
+        "e_tuple",
+        "long_tuples",
+        "Long tuples cannot be printed",
+        long_tuples,
+    ),
+    Example::new(
+        "e_tuple",
+        "destructuring_tuples",
+        "Destructuring tuples",
+        destructuring_tuples,
+    ),
+    Example::new(
+        "e_tuple",
+        "destructuring_assignments",
+        "Destructuring assignments",
+        destructuring_assignments,
+    ),
+    Example::new(
+        "e_tuple",
+        "tuples_as_function_arguments",
+        "Tuples as arguments and return values",
+        tuples_as_function_arguments,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    question!("e_tuple", { let t: (&str, &str, &str) = ("i", "am", "sunface"); } => t.2),
+    question!("e_tuple", {
+        let t = (1, 2, 3);
+        let (a, _, c) = t;
+    } => c),
+];
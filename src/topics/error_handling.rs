@@ -78,3 +78,29 @@ fn panic_example_02() {
 
 // [profile.release]
 // panic = 'abort'
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "error_handling",
+        "panic_example_01",
+        "`panic!` stops the program",
+        panic_example_01,
+    ),
+    Example::exercise(
+        "error_handling",
+        "panic_example_02",
+        "Common panic cases",
+        panic_example_02,
+    )
+    .hints(&[
+        "Each step is a classic panic: a failed `assert_eq!`, an index out of bounds, `unwrap` on `None`, an overflow and a division by zero.",
+        "In `production_rate_per_hour`, `cph` takes the type of `speed`, `u16`: with a `u8`, 2 * 221 = 442 would overflow its max value of 255.",
+        "`divide(15, 0)` would panic with \"attempt to divide by zero\", the divisor must not be 0.",
+    ]),
+];
+
+pub const QUIZ: &[Question] = &[question!("error_handling", {} => "abc".as_bytes())];
@@ -5,7 +5,7 @@
 // Have to be instanciated with data, think of it like the struct is the template for the instances you create from it.
 
 #[derive(Debug)]
-struct User {
+pub struct User {
     active: bool,
     username: String,
     email: String,
@@ -72,5 +72,29 @@ pub fn struct_partial_move() {
     let name = f.name; // Moved ownership of f.name to name
 
     // println!("{}, {}, {:?}", f.data, f.name, f); // Error: f.name was moved")
+    // See tests/compile_fail/f_structs_partial_move.rs
     println!("{}, {}", f.data, name);
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "f_structs",
+        "struct_test",
+        "Instantiating and mutating a struct",
+        struct_test,
+    ),
+    Example::new(
+        "f_structs",
+        "struct_update_syntax",
+        "Struct update syntax",
+        struct_update_syntax,
+    ),
+    Example::new(
+        "f_structs",
+        "struct_partial_move",
+        "Partial move out of a struct",
+        struct_partial_move,
+    ),
+];
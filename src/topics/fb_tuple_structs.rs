@@ -15,3 +15,12 @@ pub fn tuple_structs() {
     println!("black: {:?}", black);
     println!("origin: {:?}", origin);
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "fb_tuple_structs",
+    "tuple_structs",
+    "Declaring tuple structs",
+    tuple_structs,
+)];
@@ -194,3 +194,74 @@ pub fn from_into_exercise_04() {
 
     println!("Success!");
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::exercise(
+        "from_into_conversion",
+        "test_from_into",
+        "Implementing `From` gives `Into` for free",
+        test_from_into,
+    )
+    .hints(&[
+        "`impl From<i32> for Number` gives `Number::from(30)`.",
+        "`Into` comes for free with `From`, but the target type must be known: annotate `let num: Number`.",
+    ]),
+    Example::new(
+        "from_into_conversion",
+        "converting_string_slice_into_string",
+        "Converting &str into String",
+        converting_string_slice_into_string,
+    ),
+    Example::exercise(
+        "from_into_conversion",
+        "from_into_exercise_01",
+        "`From`/`Into` for primitive types",
+        from_into_exercise_01,
+    )
+    .hints(&[
+        "`bool` converts into integers: `false` is 0 and `true` is 1.",
+        "`char` converts into `u32` (its code point) and into `String`.",
+    ]),
+    Example::exercise(
+        "from_into_conversion",
+        "tryfrom_tryinto_exercise_01",
+        "Catching a failed `try_into`",
+        tryfrom_tryinto_exercise_01,
+    )
+    .hints(&[
+        "256 doesn't fit in a `u8` (0..=255), so `try_into` returns an `Err`.",
+        "Match on the result and fall back to 0 in the `Err` arm.",
+    ]),
+    Example::exercise(
+        "from_into_conversion",
+        "tryfrom_tryinto_exercise_02",
+        "Implementing `TryFrom` for `EvenNum`",
+        tryfrom_tryinto_exercise_02,
+    )
+    .hints(&[
+        "`TryFrom` needs an `Error` type and a `try_from` returning `Result<Self, Self::Error>`.",
+        "`value % 2 == 0` tells even numbers apart, and `try_into` comes for free once `TryFrom` is implemented.",
+    ]),
+    Example::exercise(
+        "from_into_conversion",
+        "try_into_exercise_03",
+        "Implementing Display gives `to_string`",
+        try_into_exercise_03,
+    )
+    .hints(&[
+        "`to_string` comes from `ToString`, which is implemented for every type that implements `Display`.",
+        "Write the expected text in `fmt` with `write!(f, ...)`.",
+    ]),
+    Example::exercise(
+        "from_into_conversion",
+        "from_into_exercise_04",
+        "Parsing strings with `FromStr`",
+        from_into_exercise_04,
+    )
+    .hints(&[
+        "`parse` needs to know the target type: annotate the variable or use the turbofish `::<i32>`.",
+        "`parse` is built on `FromStr`, so `i32::from_str` works too once the trait is in scope.",
+    ]),
+];
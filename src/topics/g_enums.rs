@@ -53,3 +53,20 @@ pub fn enum_test2() {
         println!("x: {}, y: {}", x, y);
     }
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "g_enums",
+        "enum_test",
+        "Enum variants holding data",
+        enum_test,
+    ),
+    Example::new(
+        "g_enums",
+        "enum_test2",
+        "Destructuring an enum variant",
+        enum_test2,
+    ),
+];
@@ -19,3 +19,19 @@ fn plus_one(x: Option<i32>) -> Option<i32> {
         Some(i) => Some(i + 1),
     }
 }
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "gb_option_enum",
+    "_example_option_enum",
+    "Matching on `Option`",
+    _example_option_enum,
+)];
+
+pub const QUIZ: &[Question] = &[
+    question!("gb_option_enum", { let five = Some(5); } => plus_one(five)),
+    question!("gb_option_enum", {} => plus_one(None)),
+];
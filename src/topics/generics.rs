@@ -67,6 +67,21 @@ impl<T: Clone, U: Clone> Point2<T, U> {
 
 // let p = Point2 { x: 5, y: 10.0 }; // T is i32, U is f64
 
+pub fn point_mixup_example() {
+    let generic_point1 = Point2 { x: 5, y: 10.0 };
+    let generic_point2 = Point2 { x: "Point", y: '#' };
+
+    let mixed_associated_point = Point2::mixup_associated(&generic_point1, &generic_point2);
+    println!(
+        "Mixed Associated Point: x = {}, y = {}",
+        mixed_associated_point.x, mixed_associated_point.y
+    );
+
+    let mixed_point = generic_point1.mixup(generic_point2);
+
+    println!("Mixed Point: x = {}, y = {}", mixed_point.x, mixed_point.y);
+}
+
 pub struct Val<T> {
     pub val: T,
 }
@@ -77,8 +92,55 @@ impl<T> Val<T> {
     }
 }
 
+pub fn generic_value_example() {
+    let x = Val { val: 5 };
+    let y = Val {
+        val: "hello".to_string(),
+    };
+    println!("x: {}, y: {}", x.value(), y.value());
+}
+
 // CONST GEMERICS
 #[derive(Debug)]
 pub struct Array<T, const N: usize> {
     pub data: [T; N],
 }
+
+pub fn const_generics_example() {
+    let const_generics_arr = [
+        Array { data: [1, 2, 3] },
+        Array { data: [5, 4, 3] },
+        Array { data: [10, 20, 30] },
+    ];
+
+    println!("Const Generics Array: {:?}", const_generics_arr);
+}
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "generics",
+        "test_generics",
+        "Generic and non-generic functions",
+        test_generics,
+    ),
+    Example::new(
+        "generics",
+        "point_mixup_example",
+        "Mixing the fields of two generic points",
+        point_mixup_example,
+    ),
+    Example::new(
+        "generics",
+        "generic_value_example",
+        "Generic struct with a method",
+        generic_value_example,
+    ),
+    Example::new(
+        "generics",
+        "const_generics_example",
+        "Arrays with const generics",
+        const_generics_example,
+    ),
+];
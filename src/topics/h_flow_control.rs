@@ -118,3 +118,63 @@ pub fn nested_loops_example() {
         }
     }
 }
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "h_flow_control",
+        "for_loop_example",
+        "`for` loop with `enumerate`",
+        for_loop_example,
+    ),
+    Example::new(
+        "h_flow_control",
+        "while_loop_example",
+        "FizzBuzz with a `while` loop",
+        while_loop_example,
+    ),
+    Example::new(
+        "h_flow_control",
+        "continue_break_loop_example",
+        "`continue` and `break`",
+        continue_break_loop_example,
+    ),
+    Example::new(
+        "h_flow_control",
+        "loop_example",
+        "Returning a value from `loop`",
+        loop_example,
+    ),
+    Example::new(
+        "h_flow_control",
+        "nested_loops_example",
+        "Breaking out of labeled loops",
+        nested_loops_example,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[question!("h_flow_control", {
+    let mut count = 0;
+
+    'outer: loop {
+        'inner1: loop {
+            if count >= 20 {
+                break 'inner1;
+            }
+            count += 2;
+        }
+
+        count += 5;
+
+        '_inner2: loop {
+            if count >= 30 {
+                break 'outer;
+            }
+
+            continue 'outer;
+        }
+    }
+} => count)];
@@ -13,6 +13,9 @@ use std::{collections::HashMap, hash::Hash};
 // is very competitive for medium sized keys, other hashing algorithms will outperform it for small keys such as integers as well as large
 // keys such as long strings, though those algorithms will typically not protect against attack such as HashDoS.
 //
+// `collections::hashdos` shows the attack: keys that all collide under FNV-1a, and a map slowing down on them
+// while SipHash, fed the same keys, doesn't notice (`cargo bench --bench hashdos`).
+//
 // The hash table implementation is a Rust port of Google's SwissTable.
 pub fn hashmap_exercise_1() {
     let mut scores = HashMap::new();
@@ -145,8 +148,165 @@ pub fn hashmap_excercise_5() {
     println!("Success!");
 }
 
+// SwissTable
+//
+// `collections::swiss::HashMap` is a small SwissTable, written to be read (src/collections/swiss.rs): the
+// control bytes, the groups, the tombstones. It takes the same calls as std's `HashMap` for this lesson.
+// A table is an array of buckets, a power of two of them, at most 7/8 full: the 8th entry doesn't fit in 8
+// buckets, the table doubles and every entry moves to its new place.
+use crate::collections::swiss;
+pub fn swiss_table_growth() {
+    let mut scores = swiss::HashMap::new();
+    let names = [
+        "Sunface", "Daniel", "Ashley", "Katie", "Einar", "Olaf", "Harald", "Astrid", "Sigrid",
+    ];
+    for (score, name) in names.into_iter().enumerate() {
+        scores.entry(name).or_insert(score);
+        println!(
+            "{} entries -> {} buckets, room for {}",
+            scores.len(),
+            scores.buckets(),
+            scores.capacity()
+        );
+    }
+    assert_eq!(scores["Astrid"], 7);
+}
+
 // Third-party hash libs
 //
 // If the performance of `SipHash 1-3` doesn't meet your requirements, you can find replacements in crates.io or github.com.
 //
-// The usage of third-party hash looks like this:
+// The usage of third-party hash looks like this: the hasher goes in the third type parameter of `HashMap`,
+// wrapped in a `BuildHasherDefault` when it has no state of its own, and the map is created with
+// `Default::default()` or `HashMap::with_hasher`.
+//
+// `collections::hash` has three of them, written in this crate (src/collections/hash.rs): SipHash-1-3 (the
+// default), FNV-1a and Fx. `cargo bench --bench hashers` compares them on integers, long strings and
+// `Viking`s.
+use crate::collections::hash::{FnvHasher, FxBuildHasher};
+use std::hash::BuildHasherDefault;
+pub fn third_party_hasher() {
+    let mut hash: HashMap<_, _, BuildHasherDefault<FnvHasher>> = Default::default();
+    hash.insert(42, "the answer");
+    assert_eq!(hash.get(&42), Some(&"the answer"));
+
+    let mut vikings = HashMap::with_hasher(FxBuildHasher::default());
+    vikings.insert(Viking::new("Einar", "Norway"), 25);
+    assert_eq!(vikings[&Viking::new("Einar", "Norway")], 25);
+
+    println!("Success!");
+}
+
+// Many values, both ways
+//
+// Collecting tuples into a `HashMap` keeps one value per key: the last one. `collections::multimap::MultiMap`
+// keeps them all, grouped by key. `collections::bimap::BiMap` pairs values one to one and finds a pair from
+// either side, refusing a value that is already paired. Both have the entry API of `HashMap`.
+use crate::collections::{bimap::BiMap, multimap::MultiMap};
+pub fn multimap_and_bimap() {
+    let vikings = [
+        ("Norway", "Einar"),
+        ("Denmark", "Olaf"),
+        ("Norway", "Harald"),
+    ];
+    let last: HashMap<&str, &str> = vikings.into_iter().collect();
+    let all: MultiMap<&str, &str> = vikings.into_iter().collect();
+    assert_eq!(last["Norway"], "Harald");
+    assert_eq!(all["Norway"], ["Einar", "Harald"]);
+    for name in all.get_all("Norway") {
+        println!("{} is from Norway", name);
+    }
+
+    let mut ranks = BiMap::from([("Chinese Team", 1), ("France Team", 2)]);
+    assert_eq!(ranks.get_by_left("France Team"), Some(&2));
+    assert_eq!(ranks.get_by_right(&1), Some(&"Chinese Team"));
+    assert_eq!(ranks.entry_by_left("American Team").or_insert(1), Err(1));
+    assert_eq!(ranks.entry_by_left("American Team").or_insert(3), Ok(&3));
+    println!("{} teams ranked", ranks.len());
+}
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::exercise(
+        "hashmap",
+        "hashmap_exercise_1",
+        "Inserting, reading and removing entries",
+        hashmap_exercise_1,
+    )
+    .hints(&[
+        "`get` returns an `Option<&V>`, indexing with `[]` returns the value and panics on a missing key.",
+        "After removing \"Daniel\", three entries are left.",
+    ]),
+    Example::exercise(
+        "hashmap",
+        "hashmap_exercise_2",
+        "Building a HashMap from tuples",
+        hashmap_exercise_2,
+    )
+    .hints(&[
+        "An array of `(key, value)` tuples converts into a map with `HashMap::from`.",
+        "`collect()` builds a HashMap from an iterator of tuples when the type is annotated.",
+    ]),
+    Example::exercise(
+        "hashmap",
+        "hashmap_exercise_3",
+        "The entry API: `or_insert` and `or_insert_with`",
+        hashmap_exercise_3,
+    )
+    .hints(&[
+        "`entry(key)` gives access to the slot of a key, whether it exists or not.",
+        "`or_insert` only inserts when the key is missing, and returns a `&mut V` to the value either way.",
+        "Dereference that `&mut` to change the value: `*health -= 50`.",
+    ]),
+    Example::new(
+        "hashmap",
+        "hashmap_excercise_4",
+        "Custom struct as a key",
+        hashmap_excercise_4,
+    ),
+    Example::exercise(
+        "hashmap",
+        "hashmap_excercise_5",
+        "Ownership of keys and values",
+        hashmap_excercise_5,
+    )
+    .hints(&[
+        "Inserting a `Copy` value copies it, inserting a `String` moves it into the map.",
+        "Insert a reference (`&v2`) to keep using `v2` afterwards.",
+    ]),
+    Example::new(
+        "hashmap",
+        "swiss_table_growth",
+        "A SwissTable growing as entries are inserted",
+        swiss_table_growth,
+    ),
+    Example::new(
+        "hashmap",
+        "third_party_hasher",
+        "Plugging another hasher into a HashMap",
+        third_party_hasher,
+    ),
+    Example::new(
+        "hashmap",
+        "multimap_and_bimap",
+        "One key with many values, and lookups both ways",
+        multimap_and_bimap,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    question!("hashmap", {
+        let mut scores = HashMap::new();
+        scores.insert("Sunface", 98);
+        scores.insert("Daniel", 95);
+    } => scores.get("Sunface")),
+    question!("hashmap", {
+        let mut player_stats = HashMap::new();
+        player_stats.entry("health").or_insert(100);
+        let health = player_stats.entry("health").or_insert(50);
+        *health -= 50;
+    } => player_stats["health"]),
+];
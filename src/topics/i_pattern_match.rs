@@ -147,3 +147,70 @@ pub fn match_ignoring_remaining_parts() {
         }
     }
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "i_pattern_match",
+        "pattern_match_example",
+        "Matching on an enum",
+        || {
+            pattern_match_example(Coin::Dime);
+        },
+    ),
+    Example::exercise(
+        "i_pattern_match",
+        "matches_example",
+        "The `matches!` macro with ranges",
+        matches_example,
+    )
+    .hints(&[
+        "`matches!(value, pattern)` is a `match` that returns a bool.",
+        "Patterns can be ranges combined with `|`: `'A'..='Z' | 'a'..='z' | '0'..='9'`.",
+    ]),
+    Example::exercise(
+        "i_pattern_match",
+        "matches_example_2",
+        "Counting enum variants with `matches!`",
+        matches_example_2,
+    )
+    .hints(&[
+        "An enum without `PartialEq` can't be compared with `==`, but `matches!` works with any pattern.",
+        "Count the elements for which `matches!(e, MyEnum::Foo)` is true.",
+    ]),
+    Example::new(
+        "i_pattern_match",
+        "match_example_2",
+        "Matching ranges of numbers",
+        || match_example_2(7),
+    ),
+    Example::exercise(
+        "i_pattern_match",
+        "match_example_3",
+        "Binding values with `@`",
+        match_example_3,
+    )
+    .hints(&[
+        "`@` binds the matched value to a name while testing it against a pattern.",
+        "`y: y @ (10 | 20 | 30)` only matches one of the three values, and names it `y`.",
+    ]),
+    Example::new(
+        "i_pattern_match",
+        "match_example_4",
+        "Binding enum fields with `@`",
+        match_example_4,
+    ),
+    Example::new(
+        "i_pattern_match",
+        "match_guard_example",
+        "Match guards",
+        match_guard_example,
+    ),
+    Example::new(
+        "i_pattern_match",
+        "match_ignoring_remaining_parts",
+        "Ignoring parts of a value with `..`",
+        match_ignoring_remaining_parts,
+    ),
+];
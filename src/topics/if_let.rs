@@ -26,3 +26,28 @@ pub fn _if_let_example_2() {
     }
     println!("Success!");
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::exercise(
+        "if_let",
+        "_if_let_example",
+        "Unwrapping an Option with `if let`",
+        _if_let_example,
+    )
+    .hints(&[
+        "`if let` is a `match` with only the arm you care about.",
+        "`if let Some(i) = o` runs its block only when `o` is `Some`, with the value in `i`.",
+    ]),
+    Example::exercise(
+        "if_let",
+        "_if_let_example_2",
+        "`if let` with an `else` branch",
+        _if_let_example_2,
+    )
+    .hints(&[
+        "`if let` can have an `else` branch for every other variant.",
+        "`if let Foo::Bar(i) = a` binds the `u8` inside `Bar`.",
+    ]),
+];
@@ -249,3 +249,101 @@ pub fn _example_iterators_10() {
 
     assert_eq!(v2, vec![2, 3, 4])
 }
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "iterators",
+        "_example_iterators_01",
+        "`for` loops over iterators",
+        _example_iterators_01,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_01_1",
+        "`for` calls `into_iter`",
+        _example_iterators_01_1,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_01_2",
+        "Iterating over an array",
+        _example_iterators_01_2,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_01_3",
+        "Pushing items from a range",
+        _example_iterators_01_3,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_02",
+        "Calling `next` directly",
+        _example_iterators_02,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_03",
+        "`into_iter` vs `iter`",
+        _example_iterators_03,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_04",
+        "Mutating items with `iter_mut`",
+        _example_iterators_04,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_05",
+        "Mutating through `next` on `iter_mut`",
+        _example_iterators_05,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_06",
+        "Implementing Iterator for a counter",
+        _example_iterators_06,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_07",
+        "A Fibonacci iterator",
+        _example_iterators_07,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_08",
+        "Consuming adaptors: `sum`",
+        _example_iterators_08,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_09",
+        "Collecting into a HashMap and a Vec",
+        _example_iterators_09,
+    ),
+    Example::new(
+        "iterators",
+        "_example_iterators_10",
+        "Iterator adaptors: `map`",
+        _example_iterators_10,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    question!("iterators", { let mut v1 = vec![1, 2].into_iter(); } => v1.next()),
+    question!("iterators", {
+        let v1 = vec![1, 2, 3];
+        let v2: Vec<i32> = v1.iter().map(|e| e + 1).collect();
+    } => v2),
+    question!("iterators", {
+        let v1 = vec![1, 2, 3];
+        let v1_iter = v1.iter();
+        let total: i32 = v1_iter.sum();
+    } => total),
+];
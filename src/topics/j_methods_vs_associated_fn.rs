@@ -44,8 +44,8 @@ const REACT1: Rectangle = Rectangle {
 // This means that the instance will not be moved, and we can use it again after calling the method.
 
 #[derive(Debug)]
-struct TrafficLight {
-    color: String,
+pub struct TrafficLight {
+    pub color: String,
 }
 
 impl TrafficLight {
@@ -74,3 +74,17 @@ impl TrafficLightColorEnum {
         }
     }
 }
+
+pub fn traffic_light_color_example() {
+    let color = TrafficLightColorEnum::Yellow;
+    println!("The color of the traffic light is: {}", color.color());
+}
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "j_methods_vs_associated_fn",
+    "traffic_light_color_example",
+    "Calling a method on an enum",
+    traffic_light_color_example,
+)];
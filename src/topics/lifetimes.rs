@@ -17,7 +17,7 @@ fn example_dangling_reference_01() {
 
     {
         let x = 5;
-        // r = &x; // `x` does not live long enough.
+        // r = &x; // `x` does not live long enough. (tests/compile_fail/lifetimes_dangling_reference.rs)
     }
 
     // println!("r: {}", r);
@@ -414,3 +414,72 @@ pub fn _example_static_lifetime_04() {
     // but this one works!
     _print_it2(&i);
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "lifetimes",
+        "example_dangling_reference_01",
+        "A reference must not outlive its value",
+        example_dangling_reference_01,
+    ),
+    Example::new(
+        "lifetimes",
+        "example_lifetimes_01",
+        "Disjoint borrows of the same value",
+        example_lifetimes_01,
+    ),
+    Example::new(
+        "lifetimes",
+        "example_lifetime_annotating_01",
+        "Annotating lifetimes in functions",
+        example_lifetime_annotating_01,
+    ),
+    Example::new(
+        "lifetimes",
+        "example_lifetime_annotation_02",
+        "Returning the longest of two strings",
+        example_lifetime_annotation_02,
+    ),
+    Example::new(
+        "lifetimes",
+        "example_lifetime_annotating_03",
+        "Returning a `'static` reference",
+        example_lifetime_annotating_03,
+    ),
+    Example::new(
+        "lifetimes",
+        "example_lifetime_annotating_04",
+        "Lifetimes on methods",
+        example_lifetime_annotating_04,
+    ),
+    Example::new(
+        "lifetimes",
+        "_example_static_lifetime_01",
+        "String literals are `'static`",
+        _example_static_lifetime_01,
+    ),
+    Example::exercise(
+        "lifetimes",
+        "_example_static_lifetime_02",
+        "Passing literals to a `'static` parameter",
+        _example_static_lifetime_02,
+    )
+    .hints(&[
+        "String literals are hardcoded into the binary, so they are `&'static str`.",
+        "A `String` built at runtime can't be passed where a `&'static str` is needed, a literal can.",
+    ]),
+    Example::new(
+        "lifetimes",
+        "_example_static_lifetime_03",
+        "The data lives forever, the reference does not",
+        _example_static_lifetime_03,
+    ),
+    Example::new(
+        "lifetimes",
+        "_example_static_lifetime_04",
+        "`T: 'static` as a trait bound",
+        _example_static_lifetime_04,
+    ),
+];
@@ -18,3 +18,7 @@
 // - Accessing data on the heap is also slower, as it has to be accessed usign a pointer which points to an address.
 // - Heap memory is a region of memory that stores data in a more flexible way.
 //
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
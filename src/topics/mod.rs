@@ -41,3 +41,301 @@ pub mod traits;
 pub mod type_coersion;
 pub mod unit_like_structs;
 pub mod vectors;
+
+use crate::registry::Topic;
+
+// Topics with `requires` name the topics that should be learned before them, see `curriculum`.
+pub const TOPICS: &[Topic] = &[
+    Topic::new(
+        "aa_ownership",
+        "Ownership",
+        aa_ownership::EXAMPLES,
+        include_str!("aa_ownership.rs"),
+    )
+    .requires(&["memory", "scope"]),
+    Topic::new(
+        "ab_ownership_vs_functions",
+        "Ownership and Functions",
+        ab_ownership_vs_functions::EXAMPLES,
+        include_str!("ab_ownership_vs_functions.rs"),
+    )
+    .requires(&["aa_ownership"]),
+    Topic::new("array", "Arrays", array::EXAMPLES, include_str!("array.rs")),
+    Topic::new(
+        "associated_function",
+        "Associated Functions",
+        associated_function::EXAMPLES,
+        include_str!("associated_function.rs"),
+    )
+    .requires(&["f_structs"]),
+    Topic::new(
+        "associated_types",
+        "Associated Types",
+        associated_types::EXAMPLES,
+        include_str!("associated_types.rs"),
+    )
+    .requires(&["generics", "traits"]),
+    Topic::new(
+        "b_borrowing",
+        "Borrowing",
+        b_borrowing::EXAMPLES,
+        include_str!("b_borrowing.rs"),
+    )
+    .requires(&["ab_ownership_vs_functions"]),
+    Topic::new("box_1", "Box", box_1::EXAMPLES, include_str!("box_1.rs"))
+        .requires(&["b_borrowing", "memory"]),
+    Topic::new(
+        "c_string_vs_string_slice",
+        "String vs String Slice",
+        c_string_vs_string_slice::EXAMPLES,
+        include_str!("c_string_vs_string_slice.rs"),
+    )
+    .requires(&["b_borrowing"])
+    .quiz(c_string_vs_string_slice::QUIZ),
+    Topic::new(
+        "cargo",
+        "Cargo, Crates and Modules",
+        cargo::EXAMPLES,
+        include_str!("cargo.rs"),
+    ),
+    Topic::new(
+        "closures",
+        "Closures",
+        closures::EXAMPLES,
+        include_str!("closures.rs"),
+    )
+    .requires(&["b_borrowing", "traits"])
+    .quiz(closures::QUIZ),
+    Topic::new(
+        "copy_vs_move",
+        "Copy vs Move",
+        copy_vs_move::EXAMPLES,
+        include_str!("copy_vs_move.rs"),
+    )
+    .requires(&["aa_ownership"]),
+    Topic::new(
+        "d_slice",
+        "Slices",
+        d_slice::EXAMPLES,
+        include_str!("d_slice.rs"),
+    )
+    .requires(&["array", "c_string_vs_string_slice"])
+    .quiz(d_slice::QUIZ),
+    Topic::new(
+        "debug_display",
+        "Debug and Display",
+        debug_display::EXAMPLES,
+        include_str!("debug_display.rs"),
+    )
+    .requires(&["traits"]),
+    Topic::new(
+        "deep_copy",
+        "Deep Copy",
+        deep_copy::EXAMPLES,
+        include_str!("deep_copy.rs"),
+    )
+    .requires(&["copy_vs_move"]),
+    Topic::new(
+        "derivable_traits",
+        "Derivable Traits",
+        derivable_traits::EXAMPLES,
+        include_str!("derivable_traits.rs"),
+    )
+    .requires(&["traits"]),
+    Topic::new(
+        "dynamic_dispatch",
+        "Dynamic Dispatch",
+        dynamic_dispatch::EXAMPLES,
+        include_str!("dynamic_dispatch.rs"),
+    )
+    .requires(&["box_1", "traits"]),
+    Topic::new(
+        "e_tuple",
+        "Tuples",
+        e_tuple::EXAMPLES,
+        include_str!("e_tuple.rs"),
+    )
+    .quiz(e_tuple::QUIZ),
+    Topic::new(
+        "error_handling",
+        "Error Handling",
+        error_handling::EXAMPLES,
+        include_str!("error_handling.rs"),
+    )
+    .requires(&["result_class"])
+    .quiz(error_handling::QUIZ),
+    Topic::new(
+        "f_structs",
+        "Structs",
+        f_structs::EXAMPLES,
+        include_str!("f_structs.rs"),
+    )
+    .requires(&["aa_ownership"]),
+    Topic::new(
+        "fb_tuple_structs",
+        "Tuple Structs",
+        fb_tuple_structs::EXAMPLES,
+        include_str!("fb_tuple_structs.rs"),
+    )
+    .requires(&["e_tuple", "f_structs"]),
+    Topic::new(
+        "from_into_conversion",
+        "From and Into Conversions",
+        from_into_conversion::EXAMPLES,
+        include_str!("from_into_conversion.rs"),
+    )
+    .requires(&["result_class", "traits"]),
+    Topic::new(
+        "g_enums",
+        "Enums",
+        g_enums::EXAMPLES,
+        include_str!("g_enums.rs"),
+    )
+    .requires(&["f_structs"]),
+    Topic::new(
+        "gb_option_enum",
+        "The Option Enum",
+        gb_option_enum::EXAMPLES,
+        include_str!("gb_option_enum.rs"),
+    )
+    .requires(&["g_enums"])
+    .quiz(gb_option_enum::QUIZ),
+    Topic::new(
+        "generics",
+        "Generics",
+        generics::EXAMPLES,
+        include_str!("generics.rs"),
+    )
+    .requires(&["f_structs", "g_enums"]),
+    Topic::new(
+        "h_flow_control",
+        "Flow Control",
+        h_flow_control::EXAMPLES,
+        include_str!("h_flow_control.rs"),
+    )
+    .quiz(h_flow_control::QUIZ),
+    Topic::new(
+        "hashmap",
+        "HashMap",
+        hashmap::EXAMPLES,
+        include_str!("hashmap.rs"),
+    )
+    .requires(&["gb_option_enum", "string", "vectors"])
+    .quiz(hashmap::QUIZ),
+    Topic::new(
+        "i_pattern_match",
+        "Pattern Matching",
+        i_pattern_match::EXAMPLES,
+        include_str!("i_pattern_match.rs"),
+    )
+    .requires(&["g_enums", "h_flow_control"]),
+    Topic::new(
+        "if_let",
+        "if let",
+        if_let::EXAMPLES,
+        include_str!("if_let.rs"),
+    )
+    .requires(&["gb_option_enum", "i_pattern_match"]),
+    Topic::new(
+        "iterators",
+        "Iterators",
+        iterators::EXAMPLES,
+        include_str!("iterators.rs"),
+    )
+    .requires(&["closures", "vectors"])
+    .quiz(iterators::QUIZ),
+    Topic::new(
+        "j_methods_vs_associated_fn",
+        "Methods vs Associated Functions",
+        j_methods_vs_associated_fn::EXAMPLES,
+        include_str!("j_methods_vs_associated_fn.rs"),
+    )
+    .requires(&["associated_function"]),
+    Topic::new(
+        "lifetimes",
+        "Lifetimes",
+        lifetimes::EXAMPLES,
+        include_str!("lifetimes.rs"),
+    )
+    .requires(&["b_borrowing", "generics"]),
+    Topic::new(
+        "memory",
+        "Stack and Heap Memory",
+        memory::EXAMPLES,
+        include_str!("memory.rs"),
+    ),
+    Topic::new(
+        "result_class",
+        "The Result Enum",
+        result_class::EXAMPLES,
+        include_str!("result_class.rs"),
+    )
+    .requires(&["gb_option_enum", "i_pattern_match"])
+    .quiz(result_class::QUIZ),
+    Topic::new("scope", "Scope", scope::EXAMPLES, include_str!("scope.rs")),
+    Topic::new(
+        "static_dispatch",
+        "Static Dispatch",
+        static_dispatch::EXAMPLES,
+        include_str!("static_dispatch.rs"),
+    )
+    .requires(&["generics", "traits"]),
+    Topic::new(
+        "static_vs_const",
+        "static vs const",
+        static_vs_const::EXAMPLES,
+        include_str!("static_vs_const.rs"),
+    )
+    .requires(&["memory"]),
+    Topic::new(
+        "static_vs_dynamic_dispatch",
+        "Static vs Dynamic Dispatch",
+        static_vs_dynamic_dispatch::EXAMPLES,
+        include_str!("static_vs_dynamic_dispatch.rs"),
+    )
+    .requires(&["dynamic_dispatch", "static_dispatch"]),
+    Topic::new(
+        "string",
+        "String",
+        string::EXAMPLES,
+        include_str!("string.rs"),
+    )
+    .requires(&["c_string_vs_string_slice"])
+    .quiz(string::QUIZ),
+    Topic::new(
+        "trait_object",
+        "Trait Objects",
+        trait_object::EXAMPLES,
+        include_str!("trait_object.rs"),
+    )
+    .requires(&["box_1", "traits"]),
+    Topic::new(
+        "traits",
+        "Traits",
+        traits::EXAMPLES,
+        include_str!("traits.rs"),
+    )
+    .requires(&["j_methods_vs_associated_fn"]),
+    Topic::new(
+        "type_coersion",
+        "Type Coercion",
+        type_coersion::EXAMPLES,
+        include_str!("type_coersion.rs"),
+    )
+    .requires(&["from_into_conversion"])
+    .quiz(type_coersion::QUIZ),
+    Topic::new(
+        "unit_like_structs",
+        "Unit-like Structs",
+        unit_like_structs::EXAMPLES,
+        include_str!("unit_like_structs.rs"),
+    )
+    .requires(&["f_structs"]),
+    Topic::new(
+        "vectors",
+        "Vectors",
+        vectors::EXAMPLES,
+        include_str!("vectors.rs"),
+    )
+    .requires(&["array", "b_borrowing"]),
+];
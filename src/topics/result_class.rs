@@ -101,3 +101,43 @@ fn add_two_and_then(n_str: &str) -> Result<i32, ParseIntError> {
 }
 
 //
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "result_class",
+        "result_example_01",
+        "Matching on a `Result`",
+        result_example_01,
+    ),
+    Example::new(
+        "result_class",
+        "result_example_02",
+        "Propagating errors with `match`",
+        || result_example_02().unwrap(),
+    ),
+    Example::new(
+        "result_class",
+        "question_mark_operator_example_01",
+        "Propagating errors with `?`",
+        || question_mark_operator_example_01().unwrap(),
+    ),
+    Example::exercise(
+        "result_class",
+        "question_mark_operator_example_02",
+        "Using `?` in a helper function",
+        question_mark_operator_example_02,
+    )
+    .hints(&[
+        "`?` returns the `Err` early and unwraps the `Ok` value.",
+        "`multiply` returns a `Result`: compare it with `Ok(20)`, or `unwrap` it to compare the number.",
+    ]),
+];
+
+pub const QUIZ: &[Question] = &[
+    question!("result_class", { let result = multiply("10", "2"); } => result),
+    question!("result_class", { let result = multiply("4", "2"); } => result.unwrap()),
+];
@@ -13,3 +13,12 @@ pub fn print_scope() {
 }
 
 // The variable `s` is only accessible within the block of code where it is defined.
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "scope",
+    "print_scope",
+    "Variables live in the scope they are declared",
+    print_scope,
+)];
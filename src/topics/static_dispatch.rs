@@ -37,3 +37,15 @@ impl CatCompilerGenerated {
         println!("Meow");
     }
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[Example::new(
+    "static_dispatch",
+    "sound",
+    "Methods resolved at compile time",
+    || {
+        Dog.sound();
+        Cat.sound();
+    },
+)];
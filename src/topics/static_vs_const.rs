@@ -20,3 +20,7 @@ const MAX_USERS: u32 = 100; // Valor fixo e imutável durante toda a execução.
 // Resumo:
 // const  -> valor fixo, sem endereço garantido, usado como substituição no código.
 // static -> valor fixo com endereço garantido, vive durante todo o programa.
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
@@ -39,3 +39,7 @@
 //     at compile time (like trait objects), while providing ownership and heap allocation.
 //     It’s commonly used for dynamic dispatch when you need to return or store different types
 //     that implement the same trait, but their sizes are not known at compile time.
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
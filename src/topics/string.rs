@@ -26,6 +26,10 @@
 // 3        |  l
 // 4        |  o
 
+// Inserting in the middle of a String moves every byte after the insertion point to make room: fine for a
+// line, slow for a whole document edited everywhere. `text::Rope` keeps the text in chunks instead
+// (`cargo bench --bench rope` compares both).
+
 fn move_ownership(s: String) {
     println!("ownership of \"{}\" is moved here!", s)
 }
@@ -60,6 +64,7 @@ pub fn exercise_3() {
     let s = String::from("hello, ぁまピ");
     let slice1 = &s[..1]; // ASCII char like `h` only takes 1 byte in UTF8 format
 
+    // `text::char_slice(&s, 7, 8)` finds the same bytes from the char index, see example_4
     let slice2 = &s[7..10]; // Unicode char like `ぁ` takes 3 bytes in UTF8 format
     println!("Slice2: {}", slice2);
     assert_eq!(slice2, "ぁ");
@@ -73,16 +78,27 @@ pub fn exercise_3() {
     println!("Success!")
 }
 
-// You can use utf8_slice to slice UTF8 string, it can index chars instead of bytes
-use utf8_slice;
+// `crate::text` counts in chars instead of bytes: `char_slice` takes chars `4..5`, which is the 4 bytes of 🚀.
+// Slicing bytes inside of the 🚀 panics, `text::substring` returns `None` instead.
+// A terminal draws 🚀 and 🌘 two columns wide, `text::width` counts columns.
+use crate::text;
 pub fn example_4() {
     let s = "The 🚀 goes to the 🌘";
 
-    let uft8_slice = utf8_slice::slice(s, 4, 5);
-    println!("UTF8 Slice - {}", uft8_slice);
+    let char_slice = text::char_slice(s, 4, 5).unwrap_or_default();
+    println!("Char Slice - {}", char_slice);
 
     let bytes_slice = &s[4..8];
     println!("Bytes Slice - {}", bytes_slice);
+
+    println!("Substring 4..6 - {:?}", text::substring(s, 4, 6));
+    println!("Char 18 starts at byte {:?}", text::char_to_byte(s, 18));
+    println!(
+        "{} bytes, {} chars, {} columns",
+        s.len(),
+        s.chars().count(),
+        text::width(s)
+    );
 }
 
 pub fn exercise_4() {
@@ -95,3 +111,110 @@ pub fn exercise_4() {
 
     assert_eq!(s, s1);
 }
+
+// Capacity
+// When a String is full, pushing onto it reallocates: a bigger buffer is asked for and the bytes may have to
+// be copied into it. The capacity doubles every time, so growing a String one piece at a time reallocates
+// again and again.
+// If the final length is known, String::with_capacity allocates the buffer once, up front.
+// `run --stats string` (built with the `alloc-stats` feature) counts the allocations of both examples.
+pub fn example_string_new_grows() {
+    let mut s = String::new();
+    for _ in 0..100 {
+        s.push_str("ab");
+    }
+
+    println!(
+        "String::new -> len: {}, capacity: {}",
+        s.len(),
+        s.capacity()
+    );
+}
+
+pub fn example_with_capacity() {
+    let mut s = String::with_capacity(200);
+    for _ in 0..100 {
+        s.push_str("ab");
+    }
+
+    println!(
+        "String::with_capacity -> len: {}, capacity: {}",
+        s.len(),
+        s.capacity()
+    );
+}
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::exercise(
+        "string",
+        "exercise_1",
+        "Pushing into a String",
+        exercise_1,
+    )
+    .hints(&[
+        "Read the `left` and `right` values of the failed assertion: what does `s` really contain?",
+        "`push_str` appends \"world\" and `push` appends '!', so `s` is \"hello, world!\".",
+        "The expected value is misspelled, compare `s` with \"hello, world!\".",
+    ]),
+    Example::exercise(
+        "string",
+        "exercise_2",
+        "Slicing and mutating a String",
+        exercise_2,
+    )
+    .hints(&[
+        "`slice3` is a `&mut String`: what is pushed through it changes `s` itself.",
+        "The last assertion expects the string to end with '!', check which char is pushed.",
+        "Push '!' instead of 'l'.",
+    ]),
+    Example::exercise(
+        "string",
+        "exercise_3",
+        "Multi-byte characters in a String",
+        exercise_3,
+    )
+    .hints(&[
+        "String indices are byte offsets, not character positions.",
+        "\"hello, \" is 7 ASCII bytes and `ぁ` takes 3 bytes in UTF-8, so it lives at bytes 7..10.",
+        "`chars().enumerate()` counts characters instead of bytes, `ぁ` is the 8th one (index 7).",
+    ]),
+    Example::new(
+        "string",
+        "example_4",
+        "Slicing by char index with `text`",
+        example_4,
+    ),
+    Example::exercise(
+        "string",
+        "exercise_4",
+        "Building a String from bytes",
+        exercise_4,
+    )
+    .hints(&[
+        "`String::from_utf8` turns the bytes into \"hello\": which of the two strings is still empty?",
+        "`s` is never written after `String::new()`.",
+        "Build `s` from the bytes too, or push `s1` into it with `s.push_str(&s1)`.",
+    ]),
+    Example::new(
+        "string",
+        "example_string_new_grows",
+        "A String growing from `String::new` reallocates",
+        example_string_new_grows,
+    ),
+    Example::new(
+        "string",
+        "example_with_capacity",
+        "`String::with_capacity` allocates once",
+        example_with_capacity,
+    ),
+];
+
+pub const QUIZ: &[Question] = &[
+    question!("string", { let s = String::from("hello, ぁまピ"); } => &s[7..10]),
+    question!("string", { let s = String::from("hello, ぁまピ"); } => s.len()),
+    question!("string", { let s = String::from("hello, ぁまピ"); } => s.chars().count()),
+];
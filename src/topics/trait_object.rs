@@ -12,7 +12,7 @@ struct Sheep {}
 #[derive(Debug)]
 struct Cow {}
 
-trait Animal {
+pub trait Animal {
     fn noise(&self) -> String;
 }
 
@@ -36,6 +36,7 @@ fn random_animal(random_number: f64) -> impl Animal {
     } else {
         Sheep {} // Cow {} This will not compile, because we are returning different types.
                  // We can use a trait object to return different types that implement the same trait.
+                 // See tests/compile_fail/trait_object_impl_trait_two_types.rs
     }
 }
 
@@ -111,6 +112,16 @@ pub fn return_animal(s: &str) -> Box<dyn Actions> {
     }
 }
 
+pub fn return_animal_example() {
+    let animal = return_animal("dog");
+
+    println!("Animal: {:?}", animal);
+
+    let animal2 = reuturn_animal_2("cat");
+
+    println!("Animal2: {:?}", animal2);
+}
+
 // Array with trait objects
 pub trait Bird {
     fn quack(&self);
@@ -141,3 +152,34 @@ impl Bird for Swan {
         println!("{}", "swan swan")
     }
 }
+
+pub fn bird_array_example() {
+    let birds: [&dyn Bird; 2] = [&Duck, &Swan];
+
+    for bird in birds {
+        bird.quack();
+    }
+}
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new(
+        "trait_object",
+        "random_animal_trait_object",
+        "Returning different types with `Box<dyn Animal>`",
+        || println!("{}", random_animal_trait_object(0.7).noise()),
+    ),
+    Example::new(
+        "trait_object",
+        "return_animal_example",
+        "Returning trait objects from a match",
+        return_animal_example,
+    ),
+    Example::new(
+        "trait_object",
+        "bird_array_example",
+        "An array of `&dyn Bird`",
+        bird_array_example,
+    ),
+];
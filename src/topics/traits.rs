@@ -5,7 +5,7 @@
 
 use std::fmt::{Debug, Display};
 
-trait Animal {
+pub trait Animal {
     fn sound(&self) -> String;
 }
 
@@ -154,7 +154,7 @@ fn multiply<T: std::ops::Mul<Output = T>>(n1: T, n2: T) -> T {
 // Exercise 5:
 // Use trait as function parameters - instead of a concrete type for the item parameter, we specify the impl keyword and the trait
 // name. This parameter accepts any type that implements the specified trait.
-trait Summary {
+pub trait Summary {
     fn summarize(&self) -> String;
 }
 
@@ -233,3 +233,14 @@ impl MyTraitDyn for String {
 fn my_function_dyn(x: Box<dyn MyTraitDyn>) -> Box<dyn MyTraitDyn> {
     x.f()
 }
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::new("traits", "sound", "Traits as parameters", || {
+        sound(&Sheep, &Cow)
+    }),
+    Example::new("traits", "say_hi", "Default trait methods", || {
+        println!("{} {}", Student.say_hi(), Teacher.say_hi())
+    }),
+];
@@ -44,3 +44,18 @@ pub fn unsafe_example() {
         println!("nan as u8 is {}", f32::NAN.to_int_unchecked::<u8>());
     }
 }
+
+use crate::question;
+use crate::quiz::Question;
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
+
+// `1000 as u8` is written the way the lesson does, without a suffix, so the literal itself overflows.
+#[allow(overflowing_literals)]
+pub const QUIZ: &[Question] = &[
+    question!("type_coersion", {} => 1000 as u8),
+    question!("type_coersion", {} => -1_i8 as u8),
+    question!("type_coersion", {} => 97.132_f32 as u8),
+    question!("type_coersion", {} => 300.1_f32 as u8),
+];
@@ -1,3 +1,7 @@
 // Structs without any fields
 // Used when working with traits
 // Does not hold any data
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[];
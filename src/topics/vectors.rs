@@ -31,20 +31,25 @@ pub fn vectors_exercise_1() {
 }
 
 // How to extend a Vector using `extend` method. Extends receive a iterator.
+//
+// `TracingVec` is a `Vec` that notices each time its capacity changes (see Capacity below). Made
+// `.logged()`, it prints when pushing and extending reallocate.
+use crate::collections::tracing_vec::TracingVec;
 pub fn vectors_exercise_2() {
     let mut v1 = Vec::from([1, 2, 4]);
     v1.pop(); // [1, 2]
     v1.push(3); // [1, 2, 3]
 
-    let mut v2 = Vec::new();
+    let mut v2 = TracingVec::new();
     // here we can pass v1 values to v2.
     v2.extend(&v1);
 
     assert_eq!(v1, v2);
 
     let s1 = String::from("This chars will be extended as Vector values");
-    let mut v3 = Vec::new();
+    let mut v3 = TracingVec::new().logged();
 
+    // `extend` first reserves what `chars()` promises (a char for every 4 bytes at least), then grows as it goes.
     v3.extend(s1.chars());
 
     println!("{:?}", v3);
@@ -83,7 +88,8 @@ pub fn vectors_exercise_3() {
 }
 
 pub fn vectors_exercise_4() {
-    let mut v = Vec::from([1, 2, 3]);
+    // Built from an array, `v` has no room to spare: the first push reallocates.
+    let mut v = TracingVec::from([1, 2, 3]).logged();
 
     for i in 0..5 {
         match v.get(i) {
@@ -115,6 +121,7 @@ pub fn vectors_exercise_5() {
     (*vec_ref).push(4);
     let slice3 = &v[0..];
     // slice3.push(4); // we could not use slice to mutate the Vector 'v', this will cause compile error.
+    // See tests/compile_fail/vectors_slice_push.rs
 
     assert_eq!(slice3, &[1, 2, 3, 4]);
 
@@ -130,3 +137,113 @@ pub fn vectors_exercise_5() {
 // onto the vector will not change its capacity or cause reallocation to occur. However, if the vector's length is increased to 11, it will have
 // to reallocate, which can be slow. For this reason, it is recommended to use Vec::with_capacity whenever possible to specify how big the vector
 // is expected to get.
+//
+// `collections::tracing_vec::TracingVec` is a `Vec` that writes down each time its capacity changes, to see
+// it happen: `vectors_exercise_2` and `vectors_exercise_4` print each reallocation as it happens. By how much
+// a vector grows is up to the standard library and may change from one version to the next; what is promised
+// is that a vector never reallocates while its length stays within its capacity.
+
+pub fn capacity_example_01() {
+    let mut v = TracingVec::with_capacity(10);
+    for i in 0..10 {
+        v.push(i);
+    }
+    println!("10 pushes, {} reallocations", v.history().len());
+
+    // The 11th element doesn't fit: a bigger buffer is allocated and the 10 elements are copied over.
+    v.push(10);
+    println!("the 11th push reallocated: {}", !v.history().is_empty());
+    assert!(v.capacity() >= 11);
+}
+
+pub fn capacity_example_02() {
+    // Pushing one element at a time, the vector grows several times on the way to 1000.
+    let mut pushed = TracingVec::new();
+    for i in 0..1000 {
+        pushed.push(i);
+    }
+    println!(
+        "pushing 1000 elements reallocated more than once: {}",
+        pushed.history().len() > 1
+    );
+
+    // A range knows its length: `extend` reserves room for all of it, once.
+    let mut extended = TracingVec::new();
+    extended.extend(0..1000);
+    println!(
+        "extending with 1000 elements reallocated {} time",
+        extended.history().len()
+    );
+
+    assert_eq!(pushed, extended);
+}
+
+use crate::registry::Example;
+
+pub const EXAMPLES: &[Example] = &[
+    Example::exercise(
+        "vectors",
+        "vectors_exercise_1",
+        "Declaring vectors and converting arrays",
+        vectors_exercise_1,
+    )
+    .hints(&[
+        "`Vec::from(array)` and `vec![..]` both build a `Vec`.",
+        "`is_vec` takes the vector by value: pass a clone to keep using it.",
+        "Push the elements of `v` into `v1` so that they're equal.",
+    ]),
+    Example::exercise(
+        "vectors",
+        "vectors_exercise_2",
+        "Extending a vector",
+        vectors_exercise_2,
+    )
+    .hints(&[
+        "`pop` removes the last element and `push` appends one.",
+        "`extend` appends every element of an iterator, e.g. `v2.extend(&v1)` or `v3.extend(s1.chars())`.",
+    ]),
+    Example::exercise(
+        "vectors",
+        "vectors_exercise_3",
+        "Converting types into vectors",
+        vectors_exercise_3,
+    )
+    .hints(&[
+        "`Vec::from` and `.into()` convert arrays, `String`s and `&str`s into vectors.",
+        "A `String` turns into the `Vec<u8>` of its bytes, like `into_bytes`.",
+        "Any iterator can be collected into a `Vec`.",
+    ]),
+    Example::exercise(
+        "vectors",
+        "vectors_exercise_4",
+        "Growing a vector while indexing it",
+        vectors_exercise_4,
+    )
+    .hints(&[
+        "`v.get(i)` returns `None` past the end, where `v[i]` would panic.",
+        "Push a new element when `get` returns `None`, so `v` has 5 elements at the end.",
+    ]),
+    Example::exercise(
+        "vectors",
+        "vectors_exercise_5",
+        "Slices of a vector are read-only",
+        vectors_exercise_5,
+    )
+    .hints(&[
+        "`&v[..]` and `&v[..v.len()]` are the same slice of the whole vector.",
+        "A slice borrows the vector: it can't be mutated while the slice is still used.",
+        "Take `slice3` after the `push`, once the `&mut` borrow has ended.",
+    ]),
+    Example::new(
+        "vectors",
+        "capacity_example_01",
+        "Pushing past the capacity reallocates",
+        capacity_example_01,
+    ),
+    Example::new(
+        "vectors",
+        "capacity_example_02",
+        "Reserving once instead of growing",
+        capacity_example_02,
+    ),
+];
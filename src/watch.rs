@@ -0,0 +1,143 @@
+// # Watch Mode
+//
+// The rustlings-style loop: edit a lesson, save, and see the result right away.
+//
+// - `src/topics/*.rs` is polled for modification times, so no inotify (or any other OS notification API)
+//   is needed and it works inside containers and on network mounts.
+// - When `closures.rs` changes, the crate is rebuilt with `cargo build` and only the `closures` topic is
+//   re-run: graded if it has exercises, otherwise its examples are simply run.
+// - Compiler diagnostics and assertion failures are printed inline, straight from the child processes.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::registry;
+
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+pub struct Watcher {
+    dir: PathBuf,
+    mtimes: HashMap<PathBuf, SystemTime>,
+}
+
+impl Watcher {
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let mut watcher = Self {
+            dir: dir.into(),
+            mtimes: HashMap::new(),
+        };
+        watcher.changed()?;
+        Ok(watcher)
+    }
+
+    // Returns the files that were created or modified since the last call.
+    pub fn changed(&mut self) -> io::Result<Vec<PathBuf>> {
+        let mut changed = Vec::new();
+
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.extension().is_none_or(|ext| ext != "rs") {
+                continue;
+            }
+
+            // Gone since `read_dir`: editors save by renaming a temporary file, `git checkout` deletes.
+            let modified = match fs::metadata(&path).and_then(|m| m.modified()) {
+                Ok(modified) => modified,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                    self.mtimes.remove(&path);
+                    continue;
+                }
+                Err(e) => return Err(e),
+            };
+            if self.mtimes.insert(path.clone(), modified) != Some(modified) {
+                changed.push(path);
+            }
+        }
+
+        changed.sort();
+        Ok(changed)
+    }
+}
+
+// The project being watched: the current directory when it looks like this crate,
+// otherwise the directory the binary was built from.
+pub fn project_root() -> PathBuf {
+    let cwd = PathBuf::from(".");
+    if cwd.join("src/topics").is_dir() {
+        cwd
+    } else {
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+    }
+}
+
+pub fn watch(root: &Path) -> io::Result<()> {
+    let mut watcher = Watcher::new(root.join("src/topics"))?;
+    println!(
+        "Watching {} for changes (Ctrl-C to stop)...",
+        root.join("src/topics").display()
+    );
+
+    loop {
+        thread::sleep(POLL_INTERVAL);
+
+        let mut changed = watcher.changed()?;
+        if changed.is_empty() {
+            continue;
+        }
+
+        // Editors often write a file in several steps, wait until it settles.
+        loop {
+            thread::sleep(POLL_INTERVAL);
+            let more = watcher.changed()?;
+            if more.is_empty() {
+                break;
+            }
+            changed.extend(more);
+        }
+        changed.sort();
+        changed.dedup();
+
+        // Clear the screen, like rustlings does.
+        print!("\x1b[2J\x1b[H");
+        let topics: Vec<&str> = changed
+            .iter()
+            .filter_map(|path| path.file_stem().and_then(|s| s.to_str()))
+            .collect();
+        rerun(root, &topics)?;
+    }
+}
+
+// One build for the whole batch, then each topic is re-run.
+fn rerun(root: &Path, topics: &[&str]) -> io::Result<()> {
+    println!("==> {} changed, rebuilding...", topics.join(", "));
+
+    let build = Command::new("cargo")
+        .args(["build", "--quiet"])
+        .current_dir(root)
+        .status()?;
+    if !build.success() {
+        println!("\nFix the compiler errors above and save again.");
+        return Ok(());
+    }
+
+    for &topic in topics {
+        let command = match registry::topic(topic) {
+            Some(t) if t.exercises().next().is_some() => "grade",
+            Some(_) => "run",
+            // `mod.rs` and new modules that are not registered yet.
+            None => continue,
+        };
+
+        Command::new("cargo")
+            .args(["run", "--quiet", "--", command, topic])
+            .current_dir(root)
+            .status()?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+// # Allocation statistics
+//
+// The counters are global, so this file has a single test: nothing else runs while it measures. The test
+// harness captures what is printed (into a buffer that allocates), so the measured code doesn't print.
+// Run with `--features alloc-stats` to check the counts, without it they must stay at zero.
+
+use std::hint::black_box;
+
+use rust_trainning::alloc_stats::{self, Stats};
+
+fn push_pairs(mut s: String) -> String {
+    for _ in 0..100 {
+        s.push_str("ab");
+    }
+    s
+}
+
+// The capacities the same pushes go through, from the first one. How a `String` grows is up to std, so
+// the expected allocator calls are read from it: the first capacity is an allocation, every change
+// after it a reallocation.
+fn capacities(mut s: String) -> Vec<usize> {
+    let mut capacities = vec![s.capacity()];
+    for _ in 0..100 {
+        s.push_str("ab");
+        if s.capacity() != *capacities.last().unwrap() {
+            capacities.push(s.capacity());
+        }
+    }
+    capacities.retain(|&capacity| capacity > 0);
+    capacities
+}
+
+#[test]
+fn box_new_allocates_and_with_capacity_does_not_reallocate() {
+    let boxed = alloc_stats::measure(|| {
+        black_box(Box::new([0u64; 4]));
+    });
+    let reference = alloc_stats::measure(|| {
+        let value = [0u64; 4];
+        black_box(&value);
+    });
+    let grown = alloc_stats::measure(|| {
+        black_box(push_pairs(String::new()));
+    });
+    let reserved = alloc_stats::measure(|| {
+        black_box(push_pairs(String::with_capacity(200)));
+    });
+
+    if !alloc_stats::ENABLED {
+        for stats in [boxed.stats, reference.stats, grown.stats, reserved.stats] {
+            assert_eq!(stats, Stats::default());
+        }
+        return;
+    }
+
+    assert_eq!((boxed.stats.allocs, boxed.stats.deallocs), (1, 1));
+    assert_eq!(boxed.stats.bytes, 32);
+    assert_eq!(reference.stats, Stats::default());
+
+    let grown_capacities = capacities(String::new());
+    assert!(grown_capacities.len() > 1);
+    assert_eq!(
+        (
+            grown.stats.allocs,
+            grown.stats.reallocs,
+            grown.stats.deallocs
+        ),
+        (1, grown_capacities.len() - 1, 1)
+    );
+    // What the reallocations added on top of the first allocation: the last capacity.
+    assert_eq!(grown.stats.bytes, *grown_capacities.last().unwrap());
+
+    assert_eq!(capacities(String::with_capacity(200)), [200]);
+    assert_eq!((reserved.stats.allocs, reserved.stats.reallocs), (1, 0));
+    assert_eq!(reserved.stats.bytes, 200);
+}
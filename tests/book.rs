@@ -0,0 +1,86 @@
+// # Book
+//
+// The book is generated into a temporary directory and read back: a chapter per topic, linked from both
+// tables of contents, made of the lesson's own prose and code and nothing of its registration.
+
+use std::fs;
+use std::path::Path;
+
+use rust_trainning::book;
+use rust_trainning::registry;
+
+fn chapter(name: &str) -> String {
+    book::chapter(registry::topic(name).unwrap())
+}
+
+#[test]
+fn every_topic_gets_a_chapter_linked_from_the_contents() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("book");
+    let _ = fs::remove_dir_all(&dir);
+
+    let files = book::write(&dir).unwrap();
+    assert_eq!(files.len(), registry::topics().len() + 2);
+
+    let readme = fs::read_to_string(dir.join("README.md")).unwrap();
+    let summary = fs::read_to_string(dir.join("SUMMARY.md")).unwrap();
+    assert!(summary.starts_with("# Summary\n\n[Introduction](README.md)\n\n"));
+    for topic in registry::topics() {
+        let link = format!("- [{}]({}.md)\n", topic.title, topic.name);
+        assert!(readme.contains(&link), "{} is not in README.md", topic.name);
+        assert!(
+            summary.contains(&link),
+            "{} is not in SUMMARY.md",
+            topic.name
+        );
+
+        let chapter = fs::read_to_string(dir.join(format!("{}.md", topic.name))).unwrap();
+        assert_eq!(chapter, book::chapter(topic));
+    }
+}
+
+#[test]
+fn a_chapter_is_the_lesson_prose_and_code() {
+    let closures = chapter("closures");
+
+    assert!(
+        closures.starts_with("# Closures\n\n_Source: `src/topics/closures.rs`_\n\n## Closures\n\n")
+    );
+    // The module's `#` headings move down one level, under the chapter title.
+    assert!(closures.contains("\n### Fn Traits\n"));
+    assert!(closures.contains("```rust\npub fn _example_closures_01() {\n"));
+    assert!(closures.contains("\n## Run the examples\n\n- `cargo run -- run closures::"));
+}
+
+#[test]
+fn the_registration_is_left_out() {
+    for topic in registry::topics() {
+        let chapter = book::chapter(topic);
+        assert!(
+            !chapter.contains("use crate::registry::Example;"),
+            "{}",
+            topic.name
+        );
+        assert!(
+            !chapter.contains("use crate::quiz::Question;"),
+            "{}",
+            topic.name
+        );
+        assert!(!chapter.contains("pub const EXAMPLES"), "{}", topic.name);
+        assert_eq!(
+            chapter.matches("```").count() % 2,
+            0,
+            "{}: unclosed fence",
+            topic.name
+        );
+    }
+}
+
+#[test]
+fn tables_and_rules_keep_their_alignment() {
+    let box_1 = chapter("box_1");
+
+    assert!(box_1.contains("```text\n================================================\n```"));
+    assert!(box_1.contains(
+        "```text\nFeature                      | & (Reference)                    | Box (Box<T>)\n"
+    ));
+}
@@ -0,0 +1,129 @@
+// # Solution checker
+//
+// The solutions in `tests/solutions/` are checked the way a learner would submit them. The sandbox needs
+// `unshare -rn`: where it isn't permitted, `check` refuses to run and so these tests fail, unless
+// `RUST_TRAINNING_SKIP_SANDBOX=1` says the machine can't run them (they are skipped then, and say so).
+
+use std::env;
+use std::path::Path;
+use std::process::{Command, Output};
+
+use rust_trainning::checker;
+
+fn check(exercise: &str, solution: &str, extra: &[&str]) -> Option<Output> {
+    if let Err(e) = checker::network_isolation() {
+        assert!(
+            env::var_os("RUST_TRAINNING_SKIP_SANDBOX").is_some(),
+            "{}, set RUST_TRAINNING_SKIP_SANDBOX=1 to skip the sandbox tests",
+            e
+        );
+        eprintln!("skipped: {}", e);
+        return None;
+    }
+
+    let solution = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/solutions")
+        .join(solution);
+    let output = Command::new(env!("CARGO_BIN_EXE_rust-trainning"))
+        .arg("check")
+        .arg(exercise)
+        .arg(solution)
+        .args(extra)
+        .output()
+        .expect("failed to run the binary");
+    Some(output)
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn a_fixed_exercise_passes() {
+    let Some(output) = check("string::exercise_1", "string_exercise_1.rs", &[]) else {
+        return;
+    };
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(stdout(&output).contains("     | Success!\nPASS"));
+}
+
+#[test]
+fn items_of_the_submission_replace_the_lesson_ones() {
+    let Some(output) = check(
+        "from_into_conversion::try_into_exercise_03",
+        "point_display.rs",
+        &[],
+    ) else {
+        return;
+    };
+
+    assert!(!output.status.success());
+    assert!(
+        stdout(&output).contains("FAIL assertion `left == right` failed\n       left: \"(0, 0)\"")
+    );
+}
+
+#[test]
+fn an_assertion_with_its_own_message_is_a_failure() {
+    let Some(output) = check("string::exercise_1", "custom_message.rs", &[]) else {
+        return;
+    };
+
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("FAIL \"hello, world\" has no exclamation mark\n"));
+}
+
+#[test]
+fn a_panic_outside_of_an_assertion_is_not_a_failure() {
+    let Some(output) = check("string::exercise_1", "unwrap_none.rs", &[]) else {
+        return;
+    };
+
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("PANIC called `Option::unwrap()` on a `None` value\n"));
+}
+
+#[test]
+fn a_solution_that_never_ends_is_killed() {
+    let Some(output) = check("string::exercise_1", "endless_loop.rs", &["--timeout", "1"]) else {
+        return;
+    };
+
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("TIMEOUT after 1s"));
+}
+
+#[test]
+fn a_solution_sees_no_network() {
+    // Outside the sandbox the same connection is refused by this machine, not unreachable.
+    let error = std::net::TcpStream::connect("127.0.0.1:1").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::ConnectionRefused);
+    let Some(output) = check("string::exercise_1", "network.rs", &[]) else {
+        return;
+    };
+
+    assert!(output.status.success(), "{:?}", output);
+    assert!(stdout(&output).contains("     | Success!\nPASS"));
+}
+
+#[test]
+fn a_solution_that_allocates_too_much_is_stopped() {
+    let Some(output) = check("string::exercise_1", "memory_hog.rs", &["--memory", "64"]) else {
+        return;
+    };
+
+    assert!(!output.status.success());
+    assert!(stdout(&output).contains("OUT OF MEMORY, the limit is 64 MB"));
+}
+
+#[test]
+fn the_submission_takes_the_place_of_the_lesson_items() {
+    let lesson = "use std::fmt;\n\n// A helper.\nfn helper() -> u32 {\n    1\n}\n\nfn exercise() {\n    assert_eq!(helper(), 2);\n}\n\nuse crate::registry::Example;\n\npub const EXAMPLES: &[Example] = &[];\n";
+    let submission = "use std::fmt;\n\nfn helper() -> u32 {\n    2\n}\n";
+
+    assert_eq!(
+        checker::splice(lesson, submission, "submission.rs"),
+        "\n\n\n\n\n\n\nfn exercise() {\n    assert_eq!(helper(), 2);\n}\n\ninclude!(\"submission.rs\");\n\nuse crate::registry::Example;\n\npub const EXAMPLES: &[Example] = &[];\n"
+    );
+}
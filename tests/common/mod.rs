@@ -0,0 +1,28 @@
+// # Test helpers
+//
+// Shared by the test files that declare `mod common;`. Each of them is its own crate and uses only part
+// of this module.
+#![allow(dead_code)]
+
+// A xorshift generator: the same sequence on every run, so that a failing case can be replayed. The seed
+// must not be 0, which xorshift never leaves.
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        assert_ne!(seed, 0, "xorshift is stuck at 0");
+        Self(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    // In `0..n`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+}
@@ -0,0 +1,205 @@
+// # Compile-fail fixtures
+//
+// Many lessons teach through code that must NOT compile, and keep it commented out. The fixtures in
+// `tests/compile_fail/` are those snippets, uncommented. Each fixture declares:
+//
+// // lesson: lifetimes                      -> the topic the snippet comes from
+// // item: fn example_dangling_reference_01  -> the item it is in (or next to), the lesson must still define it
+// // snippet: r = &x;                        -> a line of the fixture that is commented out in the lesson (can be repeated)
+// // error: E0597                            -> the error code rustc must report (can be repeated)
+//
+// Every fixture is compiled with the local `rustc` (or `$RUSTC`) and the expected codes are checked, so a
+// lesson that stops failing, or fails for another reason, is caught. A lesson that drops the item or
+// the commented-out snippet, or uncomments it, no longer matches its fixture and is caught too.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use rust_trainning::lesson::{self, ItemKind};
+use rust_trainning::registry;
+
+struct Fixture {
+    path: PathBuf,
+    source: String,
+    lesson: String,
+    item: String,
+    snippets: Vec<String>,
+    errors: Vec<String>,
+}
+
+fn header_values<'a>(source: &'a str, key: &str) -> Vec<&'a str> {
+    let prefix = format!("// {}:", key);
+    source
+        .lines()
+        .filter_map(|line| line.strip_prefix(&prefix))
+        .map(str::trim)
+        .collect()
+}
+
+fn fixtures() -> Vec<Fixture> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/compile_fail");
+    let mut fixtures: Vec<_> = fs::read_dir(&dir)
+        .expect("tests/compile_fail is missing")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "rs"))
+        .map(|path| {
+            let source = fs::read_to_string(&path).unwrap();
+            let single = |key| {
+                header_values(&source, key)
+                    .first()
+                    .unwrap_or_else(|| panic!("{} has no `// {}:` header", path.display(), key))
+                    .to_string()
+            };
+            let all = |key| {
+                header_values(&source, key)
+                    .into_iter()
+                    .map(str::to_string)
+                    .collect()
+            };
+            Fixture {
+                lesson: single("lesson"),
+                item: single("item"),
+                snippets: all("snippet"),
+                errors: all("error"),
+                source,
+                path,
+            }
+        })
+        .collect();
+
+    fixtures.sort_by(|a, b| a.path.cmp(&b.path));
+    fixtures
+}
+
+// Returns whether the fixture compiled, the distinct error codes rustc reported (e.g. ["E0382"])
+// and its raw output.
+fn compile(fixture: &Fixture) -> (bool, Vec<String>, String) {
+    let rustc = std::env::var("RUSTC").unwrap_or_else(|_| "rustc".to_string());
+    let out_dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("compile_fail");
+    fs::create_dir_all(&out_dir).unwrap();
+
+    let output = Command::new(rustc)
+        .args([
+            "--edition",
+            "2021",
+            "--crate-type",
+            "lib",
+            "--emit",
+            "metadata",
+        ])
+        .arg("--out-dir")
+        .arg(&out_dir)
+        .arg(&fixture.path)
+        .output()
+        .expect("failed to invoke rustc");
+
+    let stderr = String::from_utf8_lossy(&output.stderr).into_owned();
+    let mut codes: Vec<String> = stderr
+        .lines()
+        .filter_map(|line| line.strip_prefix("error["))
+        .filter_map(|rest| rest.split_once(']'))
+        .map(|(code, _)| code.to_string())
+        .collect();
+    codes.sort();
+    codes.dedup();
+
+    (output.status.success(), codes, stderr)
+}
+
+#[test]
+fn lessons_fail_to_compile_with_the_expected_error() {
+    let fixtures = fixtures();
+    assert!(!fixtures.is_empty(), "no compile-fail fixtures found");
+
+    let mut problems = Vec::new();
+    for fixture in &fixtures {
+        let name = fixture.path.file_name().unwrap().to_string_lossy();
+        let (compiled, codes, stderr) = compile(fixture);
+
+        if compiled {
+            problems.push(format!("{}: compiled, but it should fail", name));
+            continue;
+        }
+
+        let missing: Vec<_> = fixture
+            .errors
+            .iter()
+            .filter(|e| !codes.contains(e))
+            .collect();
+        if fixture.errors.is_empty() || !missing.is_empty() {
+            problems.push(format!(
+                "{}: expected {:?}, rustc reported {:?}\n{}",
+                name, fixture.errors, codes, stderr
+            ));
+        }
+    }
+
+    assert!(problems.is_empty(), "\n{}", problems.join("\n"));
+}
+
+// `fn name` -> (Function, "name")
+fn parse_item(item: &str) -> Option<(ItemKind, &str)> {
+    let (keyword, name) = item.split_once(' ')?;
+    let kind = match keyword {
+        "fn" => ItemKind::Function,
+        "struct" | "enum" | "trait" | "type" | "union" => ItemKind::Type,
+        "impl" => ItemKind::Impl,
+        _ => return None,
+    };
+    Some((kind, name.trim()))
+}
+
+// The comment of a line, if it has one: `    // let r3 = &mut s1; // mutable borrow` -> `let r3 = &mut s1; // mutable borrow`
+fn comment(line: &str) -> Option<&str> {
+    line.split_once("//").map(|(_, comment)| comment.trim())
+}
+
+#[test]
+fn fixtures_match_their_lesson() {
+    for fixture in fixtures() {
+        let name = fixture.path.display();
+        let topic = registry::topic(&fixture.lesson)
+            .unwrap_or_else(|| panic!("{} refers to unknown topic `{}`", name, fixture.lesson));
+
+        let (kind, item) = parse_item(&fixture.item)
+            .unwrap_or_else(|| panic!("{}: `// item:` is not a `<kind> <name>`", name));
+        assert!(
+            lesson::items(topic.source)
+                .iter()
+                .any(|i| i.kind == kind && i.name == item),
+            "{} refers to `{}`, which `{}` does not define",
+            name,
+            fixture.item,
+            topic.name
+        );
+
+        assert!(
+            !fixture.snippets.is_empty(),
+            "{} has no `// snippet:`",
+            name
+        );
+        let body = lesson::body(topic.source);
+        for snippet in &fixture.snippets {
+            assert!(
+                fixture
+                    .source
+                    .lines()
+                    .any(|line| !line.trim_start().starts_with("//")
+                        && line.trim_start().starts_with(snippet.as_str())),
+                "{}: the snippet `{}` is not in the fixture's code",
+                name,
+                snippet
+            );
+            assert!(
+                body.lines()
+                    .filter_map(comment)
+                    .any(|comment| comment.starts_with(snippet.as_str())),
+                "{}: `{}` no longer has `{}` commented out",
+                name,
+                topic.name,
+                snippet
+            );
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// lesson: b_borrowing
+// item: fn _viloate_borrowing_1
+// snippet: let r3 = &mut s1;
+// snippet: println!("{} {} {}", r1, r2, r3);
+// error: E0502
+//
+// Either one mutable reference OR any number of immutable references.
+fn _viloate_borrowing_1() {
+    let mut s1 = String::from("hello");
+    let r1 = &s1;
+    let r2 = &s1;
+    let r3 = &mut s1;
+    println!("{} {} {}", r1, r2, r3);
+}
@@ -0,0 +1,13 @@
+// lesson: b_borrowing
+// item: fn _violate_borrowing_2
+// snippet: let r2 = &mut s1;
+// snippet: println!("{} {}", r1, r2);
+// error: E0499
+//
+// Only one mutable reference at a time.
+fn _violate_borrowing_2() {
+    let mut s1 = String::from("hello");
+    let r1 = &mut s1;
+    let r2 = &mut s1;
+    println!("{} {}", r1, r2);
+}
@@ -0,0 +1,12 @@
+// lesson: c_string_vs_string_slice
+// item: fn concatenate_strings
+// snippet: println!("s1: {}", s1);
+// error: E0382
+//
+// `+` takes `s1` by value, so it is moved into `s3`.
+fn concatenate_strings() {
+    let s1 = String::from("hello");
+    let s2 = String::from("world");
+    let s3 = s1 + &s2;
+    println!("s1: {}", s1);
+}
@@ -0,0 +1,11 @@
+// lesson: copy_vs_move
+// item: fn copy_vs_move
+// snippet: println!("{}", s1);
+// error: E0382
+//
+// `s1` was moved into `s2`, it is no longer the owner of the string.
+fn copy_vs_move() {
+    let s1 = String::from("Hello");
+    let s2 = s1;
+    println!("{}", s1);
+}
@@ -0,0 +1,12 @@
+// lesson: e_tuple
+// item: fn long_tuples
+// snippet: println!("too long tuple {:?}", too_long_tuple);
+// error: E0277
+//
+// `Debug` is only implemented for tuples of up to 12 elements.
+fn long_tuples() {
+    let too_long_tuple = (
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20,
+    );
+    println!("too long tuple {:?}", too_long_tuple);
+}
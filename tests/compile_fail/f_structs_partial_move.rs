@@ -0,0 +1,22 @@
+// lesson: f_structs
+// item: fn struct_partial_move
+// snippet: println!("{}, {}, {:?}", f.data, f.name, f);
+// error: E0382
+//
+// `f.name` was moved out, so `f` can no longer be used as a whole.
+#[derive(Debug)]
+struct File {
+    name: String,
+    data: String,
+}
+
+fn struct_partial_move() {
+    let f = File {
+        name: String::from("file.txt"),
+        data: String::from("Hello World!"),
+    };
+
+    let name = f.name;
+
+    println!("{}, {}, {:?}", f.data, f.name, f);
+}
@@ -0,0 +1,14 @@
+// lesson: iterators
+// item: fn _example_iterators_08
+// snippet: println!("{:?}, {:?}", v1, v1_iter);
+// error: E0382
+//
+// `sum` takes ownership of the iterator, so it cannot be used afterwards.
+fn _example_iterators_08() {
+    let v1 = vec![1, 2, 3];
+    let v1_iter = v1.iter();
+
+    let total: i32 = v1_iter.sum();
+
+    println!("{:?}, {:?}", v1, v1_iter);
+}
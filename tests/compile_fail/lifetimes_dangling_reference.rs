@@ -0,0 +1,18 @@
+// lesson: lifetimes
+// item: fn example_dangling_reference_01
+// snippet: let r;
+// snippet: r = &x;
+// snippet: println!("r: {}", r);
+// error: E0597
+//
+// `x` goes out of scope while `r` still points to it.
+fn example_dangling_reference_01() {
+    let r;
+
+    {
+        let x = 5;
+        r = &x; // `x` does not live long enough.
+    }
+
+    println!("r: {}", r);
+}
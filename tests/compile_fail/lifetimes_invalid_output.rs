@@ -0,0 +1,10 @@
+// lesson: lifetimes
+// item: fn valid_output
+// snippet: pub fn invalid_output<'a>() -> &'a String {
+// snippet: &String::from("foo")
+// error: E0515
+//
+// The `String` is dropped at the end of the function, the returned reference would dangle.
+pub fn invalid_output<'a>() -> &'a String {
+    &String::from("foo")
+}
@@ -0,0 +1,13 @@
+// lesson: lifetimes
+// item: fn _longest_3
+// snippet: fn longest_1(x: &str, y: &str) -> &str {
+// error: E0106
+//
+// Two input lifetimes and no `&self`: the elision rules cannot pick the output lifetime.
+fn longest_1(x: &str, y: &str) -> &str {
+    if x.len() > y.len() {
+        x
+    } else {
+        y
+    }
+}
@@ -0,0 +1,16 @@
+// lesson: lifetimes
+// item: fn _example_static_lifetime_04
+// snippet: _print_it(&i);
+// error: E0597
+//
+// `&i` only lives as long as the function body, so it does not satisfy `T: 'static`.
+use std::fmt::Debug;
+
+fn _print_it<T: Debug + 'static>(input: T) {
+    print!("'static value passed in is: {:?}", input);
+}
+
+fn _example_static_lifetime_04() {
+    let i = 5;
+    _print_it(&i); // `i` does not live long enough.
+}
@@ -0,0 +1,32 @@
+// lesson: trait_object
+// item: fn random_animal
+// snippet: Cow {}
+// error: E0308
+//
+// `impl Animal` stands for one single concrete type, so both branches must return the same type.
+struct Sheep {}
+struct Cow {}
+
+trait Animal {
+    fn noise(&self) -> String;
+}
+
+impl Animal for Sheep {
+    fn noise(&self) -> String {
+        "Baaaaaaah".to_string()
+    }
+}
+
+impl Animal for Cow {
+    fn noise(&self) -> String {
+        "Mooooo".to_string()
+    }
+}
+
+fn random_animal(random_number: f64) -> impl Animal {
+    if random_number < 0.5 {
+        Sheep {}
+    } else {
+        Cow {}
+    }
+}
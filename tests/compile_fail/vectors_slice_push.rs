@@ -0,0 +1,14 @@
+// lesson: vectors
+// item: fn vectors_exercise_5
+// snippet: slice3.push(4);
+// error: E0599
+//
+// Slices are read-only views, they have no `push` method.
+fn vectors_exercise_5() {
+    let mut v = vec![1, 2, 3];
+
+    let vec_ref = &mut v;
+    (*vec_ref).push(4);
+    let slice3 = &v[0..];
+    slice3.push(4); // we could not use slice to mutate the Vector 'v'.
+}
@@ -0,0 +1,83 @@
+// # Curriculum
+//
+// The prerequisites declared in `topics::TOPICS` must name existing topics and must not form a cycle,
+// otherwise there is no order to learn them in.
+
+use rust_trainning::registry::{self, Topic};
+use rust_trainning::{book, curriculum};
+
+fn position(order: &[&Topic], name: &str) -> usize {
+    order
+        .iter()
+        .position(|topic| topic.name == name)
+        .unwrap_or_else(|| panic!("`{}` is not in the curriculum", name))
+}
+
+#[test]
+fn every_topic_comes_after_its_prerequisites() {
+    let order = curriculum::curriculum().unwrap();
+    assert_eq!(order.len(), registry::topics().len());
+
+    for topic in &order {
+        for required in topic.requires {
+            assert!(
+                position(&order, required) < position(&order, topic.name),
+                "`{}` comes before its prerequisite `{}`",
+                topic.name,
+                required
+            );
+        }
+    }
+
+    assert!(position(&order, "b_borrowing") < position(&order, "lifetimes"));
+    assert!(position(&order, "traits") < position(&order, "trait_object"));
+}
+
+#[test]
+fn cycles_are_reported() {
+    let topics = [
+        Topic::new("a", "A", &[], "").requires(&["c"]),
+        Topic::new("b", "B", &[], "").requires(&["a"]),
+        Topic::new("c", "C", &[], "").requires(&["b"]),
+        Topic::new("d", "D", &[], ""),
+    ];
+
+    let error = curriculum::order(&topics).map(|_| ()).unwrap_err();
+    assert_eq!(error, "prerequisites form a cycle: a -> c -> b -> a");
+}
+
+#[test]
+fn unknown_prerequisites_are_reported() {
+    let topics = [Topic::new("a", "A", &[], "").requires(&["nope"])];
+
+    let error = curriculum::order(&topics).map(|_| ()).unwrap_err();
+    assert_eq!(error, "`a` requires unknown topic `nope`");
+}
+
+#[test]
+fn dot_has_an_edge_per_prerequisite() {
+    let dot = curriculum::dot(registry::topics());
+    let edges = dot.lines().filter(|line| line.contains(" -> ")).count();
+    let requires: usize = registry::topics().iter().map(|t| t.requires.len()).sum();
+
+    assert!(dot.starts_with("digraph curriculum {"));
+    assert!(dot.contains("\"b_borrowing\" -> \"lifetimes\";"));
+    assert_eq!(edges, requires);
+}
+
+#[test]
+fn the_book_follows_the_curriculum() {
+    let chapters = book::chapters().unwrap();
+    let names: Vec<&str> = chapters.iter().map(|topic| topic.name).collect();
+    let expected: Vec<&str> = curriculum::curriculum()
+        .unwrap()
+        .iter()
+        .map(|topic| topic.name)
+        .collect();
+    assert_eq!(names, expected);
+
+    // Not the alphabetical order: `aa_ownership` builds on `memory`, `closures` on `traits`.
+    assert!(position(&chapters, "memory") < position(&chapters, "aa_ownership"));
+    let summary = book::summary(&chapters);
+    assert!(summary.find("(traits.md)") < summary.find("(closures.md)"));
+}
@@ -0,0 +1,21 @@
+// # Panic explanations
+//
+// A panic in a lesson is followed by the lesson that explains it. The rules themselves are tested in
+// `explain_rules.rs`.
+
+use std::process::Command;
+
+#[test]
+fn run_prints_the_lesson_after_the_panic() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rust-trainning"))
+        .args(["run", "error_handling::panic_example_01"])
+        .env("RUST_BACKTRACE", "0")
+        .output()
+        .expect("failed to run the binary");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    assert!(!output.status.success());
+    assert!(stderr.contains("explicit panic\n"));
+    assert!(stderr.contains("see the lesson `error_handling`"));
+    assert!(stderr.contains("    | Panic!\n"));
+}
@@ -0,0 +1,73 @@
+// # Panic explanations: the rules
+//
+// The rules match std's panic messages, so they are checked against real panics rather than copies of
+// the messages that could go stale.
+//
+// The panic hook is silenced while the panics happen, and the hook is global to the process: this test
+// has a binary of its own, so that no other test panics without its message while it runs.
+
+use std::hint::black_box;
+use std::panic;
+
+use rust_trainning::explain;
+
+fn message_of(f: fn()) -> String {
+    let payload = panic::catch_unwind(f).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn common_panics_point_to_their_lesson() {
+    panic::set_hook(Box::new(|_| {}));
+    let cases: [(fn(), &str); 6] = [
+        (
+            || {
+                black_box(vec![1, 2, 3][black_box(99)]);
+            },
+            "error_handling",
+        ),
+        (
+            || {
+                // `+` only checks for overflow in debug builds, `strict_add` always does.
+                black_box(black_box(255u8).strict_add(1));
+            },
+            "error_handling",
+        ),
+        (
+            || {
+                black_box(15 / black_box(0));
+            },
+            "error_handling",
+        ),
+        (
+            || {
+                black_box(black_box(None::<i32>).unwrap());
+            },
+            "gb_option_enum",
+        ),
+        (
+            || {
+                black_box("x".parse::<i32>().unwrap());
+            },
+            "result_class",
+        ),
+        (
+            || {
+                black_box(&black_box("ぁ")[..1]);
+            },
+            "string",
+        ),
+    ];
+
+    let messages: Vec<String> = cases.iter().map(|(f, _)| message_of(*f)).collect();
+    let _ = panic::take_hook();
+
+    for (message, (_, topic)) in messages.iter().zip(cases) {
+        let rule = explain::classify(message);
+        assert_eq!(rule.map(|r| r.topic), Some(topic), "{}", message);
+    }
+    assert!(explain::classify("something else entirely").is_none());
+}
@@ -0,0 +1,24 @@
+// # Golden output
+//
+// Every registered example must print exactly what `tests/golden` says. After changing a lesson on
+// purpose, update the files with:
+//
+// cargo run -- golden --bless
+
+use std::process::Command;
+
+#[test]
+fn examples_print_their_expected_output() {
+    let output = Command::new(env!("CARGO_BIN_EXE_rust-trainning"))
+        .arg("golden")
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .output()
+        .expect("failed to run the binary");
+
+    assert!(
+        output.status.success(),
+        "\n{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,100 @@
+// # Grading
+//
+// A failing exercise is a failure in the table, not a crash: the grader catches the panic with its
+// message and location, keeps going, and `grade` exits with 1.
+
+use rust_trainning::harness::{self, Grader, Outcome};
+use rust_trainning::registry::Example;
+
+fn passes() {
+    let words: Vec<&str> = "solved at last".split(' ').collect();
+    assert_eq!(words.len(), 3);
+    println!("Success!");
+}
+
+fn fails() {
+    let v: Vec<i32> = (1..=3).collect();
+    assert_eq!(v.len(), 4, "the vector has {} elements", v.len());
+}
+
+fn panics() {
+    let v: Vec<i32> = (1..=3).collect();
+    let last = v.get(3).unwrap();
+    assert_eq!(*last, 3);
+}
+
+static PASSING: Example = Example::exercise("grading", "passes", "A solved exercise", passes);
+static FAILING: Example = Example::exercise("grading", "fails", "An unsolved exercise", fails);
+static PANICKING: Example = Example::exercise("grading", "panics", "A crashing exercise", panics);
+
+fn location(example: &'static Example) -> String {
+    let grader = Grader::new();
+    match grader.grade(example).outcome {
+        Outcome::Failed(failure) => failure.location.unwrap(),
+        Outcome::Passed => panic!("`{}` passed", example.id),
+    }
+}
+
+#[test]
+fn a_failing_exercise_is_reported_not_fatal() {
+    let grader = Grader::new();
+    let grades = vec![grader.grade(&FAILING), grader.grade(&PASSING)];
+    drop(grader);
+
+    match &grades[0].outcome {
+        Outcome::Failed(failure) => {
+            assert!(failure.message.contains("the vector has 3 elements"));
+            assert!(failure
+                .location
+                .as_deref()
+                .unwrap()
+                .starts_with("tests/grade.rs:"));
+        }
+        Outcome::Passed => panic!("`fails` passed"),
+    }
+    assert!(grades[1].passed());
+
+    assert_eq!(
+        harness::summary(&grades),
+        "Topic    Exercise  Result
+-------------------------
+grading  fails     FAIL
+grading  passes    PASS
+
+2 exercises, 1 passed, 1 failed
+"
+    );
+    assert_eq!(harness::exit_code(&grades), 1);
+    assert_eq!(harness::exit_code(&grades[1..]), 0);
+}
+
+#[test]
+fn assertions_are_told_apart_by_their_location() {
+    let read = |file: &str| std::fs::read_to_string(file).ok();
+
+    // `fails` has a message of its own, it's still an assertion.
+    assert!(harness::is_assertion(&location(&FAILING), read));
+    assert!(!harness::is_assertion(&location(&PANICKING), read));
+
+    let source = |_: &str| Some("fn f() {\n    let é = 1; assert!(é == 2);\n}\n".to_string());
+    assert!(harness::is_assertion("f.rs:2:16", source));
+    assert!(!harness::is_assertion("f.rs:2:5", source));
+    assert!(!harness::is_assertion("f.rs:9:1", source));
+    assert!(!harness::is_assertion("missing.rs:2:16", |_| None));
+}
+
+#[test]
+fn a_fresh_checkout_grades_clean() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_rust-trainning"))
+        .args(["grade", "error_handling"])
+        .env(
+            "RUST_TRAINNING_PROGRESS",
+            std::path::Path::new(env!("CARGO_TARGET_TMPDIR")).join("grade-progress.json"),
+        )
+        .output()
+        .expect("failed to run the binary");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", stdout);
+    assert!(stdout.contains("error_handling  panic_example_02  PASS"));
+    assert!(!stdout.contains("panic_example_01"));
+}
@@ -0,0 +1,65 @@
+// # Grapheme break conformance
+//
+// Every line of `data/ucd/GraphemeBreakTest.txt` is a string written as code points, with `÷` where a
+// cluster ends and `×` where it doesn't:
+//
+//   ÷ 0061 × 0308 ÷ 0062 ÷    #  ÷ [0.2] LATIN SMALL LETTER A (Other) × [9.0] COMBINING DIAERESIS ...
+
+use std::fs;
+use std::path::Path;
+
+use rust_trainning::text::grapheme;
+
+// The string of the line, and its clusters.
+fn parse(line: &str) -> (String, Vec<String>) {
+    let mut clusters = Vec::new();
+    let mut cluster = String::new();
+    for token in line.split_whitespace() {
+        match token {
+            "÷" if !cluster.is_empty() => clusters.push(std::mem::take(&mut cluster)),
+            "÷" | "×" => {}
+            hex => {
+                let code_point = u32::from_str_radix(hex, 16).unwrap();
+                cluster.push(char::from_u32(code_point).unwrap());
+            }
+        }
+    }
+    (clusters.concat(), clusters)
+}
+
+#[test]
+fn every_case_of_the_conformance_test() {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("data/ucd/GraphemeBreakTest.txt");
+    let text = fs::read_to_string(path).unwrap();
+
+    let mut cases = 0;
+    let mut failures = Vec::new();
+    for (number, line) in text.lines().enumerate() {
+        let (case, comment) = line.split_once('#').unwrap_or((line, ""));
+        if case.trim().is_empty() {
+            continue;
+        }
+        cases += 1;
+
+        let (s, expected) = parse(case);
+        let actual: Vec<&str> = grapheme::graphemes(&s).collect();
+        if actual != expected {
+            failures.push(format!(
+                "line {}: {}\n    expected {:?}\n    got      {:?}",
+                number + 1,
+                comment.trim(),
+                expected,
+                actual
+            ));
+        }
+    }
+
+    assert!(cases > 700, "only {} cases", cases);
+    assert!(
+        failures.is_empty(),
+        "{} of {} cases fail:\n{}",
+        failures.len(),
+        cases,
+        failures.join("\n")
+    );
+}
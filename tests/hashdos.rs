@@ -0,0 +1,48 @@
+// # HashDoS
+//
+// The colliding keys really collide under FNV-1a and only there, and a map fed with them shows it in its
+// probe lengths.
+
+use std::collections::HashSet;
+use std::hash::BuildHasher;
+
+use rust_trainning::collections::hash::{FnvBuildHasher, FxBuildHasher, SipBuildHasher};
+use rust_trainning::collections::hashdos::{
+    attack, fnv_colliding_keys, random_keys, COLLIDING_BITS,
+};
+
+fn low_bits<S: BuildHasher>(keys: &[String], build: &S) -> HashSet<u64> {
+    let mask = (1u64 << COLLIDING_BITS) - 1;
+    keys.iter().map(|key| build.hash_one(key) & mask).collect()
+}
+
+#[test]
+fn keys_collide_under_fnv_only() {
+    let keys = fnv_colliding_keys(8);
+    assert_eq!(keys.len(), 256);
+    assert_eq!(keys.iter().collect::<HashSet<_>>().len(), 256);
+    assert!(keys.iter().all(|key| key.len() == 64));
+
+    assert_eq!(low_bits(&keys, &FnvBuildHasher::default()).len(), 1);
+    assert_eq!(low_bits(&keys, &SipBuildHasher::new()).len(), 256);
+    assert_eq!(low_bits(&keys, &FxBuildHasher::default()).len(), 256);
+}
+
+#[test]
+fn probe_lengths_tell_the_attack() {
+    let keys = fnv_colliding_keys(10);
+    let fnv = attack("FNV-1a", &keys, FnvBuildHasher::default());
+    let sip = attack("SipHash-1-3", &keys, SipBuildHasher::new());
+    assert_eq!(fnv.probe_lengths.iter().sum::<usize>(), 1024);
+    assert_eq!(sip.probe_lengths.iter().sum::<usize>(), 1024);
+
+    // 1024 keys from the same bucket fill 128 groups one after the other.
+    assert_eq!(fnv.longest_probe(), 128);
+    assert!(fnv.mean_probe() > 60.0);
+    assert!(sip.longest_probe() < 8);
+    assert!(sip.mean_probe() < 1.2);
+
+    let fair = attack("FNV-1a", &random_keys(10, 7), FnvBuildHasher::default());
+    assert!(fair.longest_probe() < 8);
+    assert!(fair.to_string().starts_with("FNV-1a: 1024 keys"));
+}
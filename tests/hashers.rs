@@ -0,0 +1,102 @@
+// # Hashers
+//
+// FNV-1a and SipHash-1-3 are checked against hashes published by others: the FNV test vectors, and the
+// known hashes of CPython's test suite.
+
+use std::collections::HashMap as StdMap;
+use std::hash::{BuildHasher, Hasher};
+
+use rust_trainning::collections::hash::{
+    FnvBuildHasher, FnvHasher, FxBuildHasher, FxHasher, SipBuildHasher, SipHasher13,
+};
+use rust_trainning::collections::swiss;
+
+fn fnv(bytes: &[u8]) -> u64 {
+    let mut hasher = FnvHasher::default();
+    hasher.write(bytes);
+    hasher.finish()
+}
+
+#[test]
+fn fnv_1a_test_vectors() {
+    assert_eq!(fnv(b""), 0xcbf29ce484222325);
+    assert_eq!(fnv(b"a"), 0xaf63dc4c8601ec8c);
+    assert_eq!(fnv(b"foobar"), 0x85944171f73967e8);
+}
+
+// The known hashes of CPython's `Lib/test/test_hash.py`: CPython hashes `bytes` and `str` with
+// SipHash-1-3, keyed by `PYTHONHASHSEED`. Seed 0 is the zero key, any other seed fills the key with the
+// bytes of an LCG (`lcg_urandom` in `Python/bootstrap_hash.c`), which gives these two words for 42.
+// `'äú∑ℇ'` is hashed as its UCS-2 code units, in little endian.
+const SEED_42: (u64, u64) = (0xdc504fd368cd90af, 0xb920bb9ffe99e9c1);
+const UCS2: &[u8] = &[0xe4, 0x00, 0xfa, 0x00, 0x11, 0x22, 0x07, 0x21];
+
+fn sip(keys: (u64, u64), parts: &[&[u8]]) -> u64 {
+    let mut hasher = SipHasher13::new_with_keys(keys.0, keys.1);
+    for part in parts {
+        hasher.write(part);
+    }
+    hasher.finish()
+}
+
+#[test]
+fn sip_1_3_reference_hashes() {
+    // CPython prints them as `i64`.
+    let vectors: [((u64, u64), &[u8], i64); 5] = [
+        ((0, 0), b"abc", -4594863902769663758),
+        (SEED_42, b"abc", 3869580338025362921),
+        (SEED_42, b"abcdefghijk", 7764564197781545852),
+        ((0, 0), UCS2, -2810468059467891395),
+        (SEED_42, UCS2, -2845126246016066802),
+    ];
+    for (keys, input, expected) in vectors {
+        assert_eq!(sip(keys, &[input]), expected as u64, "{:?}", input);
+        // Written in two parts: the tail of the first must carry over.
+        let (a, b) = input.split_at(input.len() / 3);
+        assert_eq!(
+            sip(keys, &[a, b]),
+            expected as u64,
+            "{:?} in two parts",
+            input
+        );
+    }
+
+    // `new` picks its keys at random.
+    let key = ("Einar", "Norway", 25u32);
+    let (a, b) = (SipBuildHasher::new(), SipBuildHasher::new());
+    assert_ne!(a.hash_one(key), b.hash_one(key));
+}
+
+#[test]
+fn fx_hashes_a_word_at_a_time() {
+    let mut bytes = FxHasher::default();
+    bytes.write(&42u64.to_le_bytes());
+    let mut word = FxHasher::default();
+    word.write_u64(42);
+    assert_eq!(bytes.finish(), word.finish());
+    assert_ne!(FxBuildHasher::default().hash_one(1u64), 1);
+}
+
+fn fill<S: BuildHasher>(hasher: S) {
+    let mut std_map = StdMap::with_hasher(hasher);
+    for i in 0..1000 {
+        std_map.insert(format!("viking {}", i), i);
+    }
+    assert_eq!(std_map.get("viking 500"), Some(&500));
+}
+
+#[test]
+fn every_hasher_plugs_into_both_maps() {
+    fill(SipBuildHasher::new());
+    fill(FnvBuildHasher::default());
+    fill(FxBuildHasher::default());
+
+    let mut swiss_map = swiss::HashMap::with_hasher(FxBuildHasher::default());
+    for i in 0..1000u64 {
+        swiss_map.insert(i, i * 2);
+    }
+    assert_eq!(swiss_map.get(&999), Some(&1998));
+    let mut swiss_map = swiss::HashMap::with_hasher(SipBuildHasher::new());
+    swiss_map.insert("key", 1);
+    assert_eq!(swiss_map["key"], 1);
+}
@@ -0,0 +1,59 @@
+// # Hints
+//
+// Every exercise comes with hints, and `hint` reveals them one at a time in a progress file of its own.
+
+use std::path::Path;
+use std::process::Command;
+
+use rust_trainning::progress::Progress;
+use rust_trainning::registry;
+
+fn hint(progress: &Path, learner: &str, exercise: &str) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rust-trainning"))
+        .args(["hint", exercise])
+        .env("RUST_TRAINNING_PROGRESS", progress)
+        .env("RUST_TRAINNING_LEARNER", learner)
+        .output()
+        .expect("failed to run the binary");
+
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn every_exercise_has_progressive_hints() {
+    for example in registry::examples().filter(|e| e.exercise) {
+        assert!(
+            example.hints.len() >= 2,
+            "{} needs at least two hints",
+            example.path()
+        );
+    }
+}
+
+#[test]
+fn hints_are_revealed_one_at_a_time_and_recorded() {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("hints-progress.json");
+    let _ = std::fs::remove_file(&path);
+    let exercise = registry::select("string::exercise_1").unwrap()[0];
+
+    let first = hint(&path, "alice", "string::exercise_1");
+    assert!(first.contains(&format!("1/3  {}", exercise.hints[0])));
+    assert!(!first.contains(exercise.hints[1]));
+
+    hint(&path, "alice", "string::exercise_1");
+    let last = hint(&path, "alice", "string::exercise_1");
+    assert!(last.contains(exercise.hints[2]));
+    assert!(last.contains("That was the last hint."));
+
+    // A fourth call shows the same hints, it doesn't count more than there are.
+    hint(&path, "alice", "string::exercise_1");
+    hint(&path, "bob", "vectors::vectors_exercise_4");
+
+    let progress = Progress::load(&path).unwrap();
+    assert_eq!(
+        progress.learner("alice").unwrap().hints_revealed(exercise),
+        3
+    );
+    assert_eq!(progress.learner("bob").unwrap().hints_used(), 1);
+}
@@ -0,0 +1,101 @@
+// # JSON
+//
+// What the progress file goes through: a value printed (compact or pretty) parses back to itself, and
+// broken input is refused with the offset of the problem rather than read as something else.
+
+use std::collections::BTreeMap;
+
+use rust_trainning::json::{self, Value, MAX_DEPTH};
+
+fn sample() -> Value {
+    let mut exercise = BTreeMap::new();
+    exercise.insert("passed".to_string(), Value::from(true));
+    exercise.insert("attempts".to_string(), Value::from(3.0));
+    exercise.insert("last_error".to_string(), Value::Null);
+    exercise.insert("hints".to_string(), Value::from(vec![0.5, -1.0, 1e21]));
+
+    let mut root = BTreeMap::new();
+    root.insert("string::exercise_1".to_string(), Value::Object(exercise));
+    root.insert(
+        "escapes".to_string(),
+        Value::from("quote \" backslash \\ tab \t newline \n bell \u{7} é 🦀"),
+    );
+    root.insert("empty".to_string(), Value::Array(Vec::new()));
+    Value::Object(root)
+}
+
+fn error(input: &str) -> String {
+    json::parse(input).unwrap_err().to_string()
+}
+
+#[test]
+fn printed_values_parse_back() {
+    let value = sample();
+
+    assert_eq!(json::parse(&value.to_string()).unwrap(), value);
+    assert_eq!(json::parse(&value.pretty()).unwrap(), value);
+}
+
+#[test]
+fn escapes_are_decoded() {
+    assert_eq!(
+        json::parse(r#""\"\\\/\b\f\n\r\tAé""#).unwrap(),
+        Value::from("\"\\/\u{8}\u{c}\n\r\tAé")
+    );
+    // A character outside of the BMP, as a surrogate pair.
+    assert_eq!(json::parse(r#""\ud83e\udd80""#).unwrap(), Value::from("🦀"));
+}
+
+#[test]
+fn unicode_escapes_take_four_hex_digits() {
+    assert_eq!(error(r#""\u+041""#), "invalid unicode escape at byte 3");
+    assert_eq!(error(r#""\u004""#), "invalid unicode escape at byte 3");
+    assert_eq!(error(r#""\u00g1""#), "invalid unicode escape at byte 3");
+}
+
+#[test]
+fn surrogates_must_come_in_pairs() {
+    // A high surrogate followed by something that is not a low one.
+    assert_eq!(
+        error(r#""\ud83e\u0041""#),
+        "invalid surrogate pair at byte 13"
+    );
+    assert_eq!(
+        error(r#""\ud83e\ud83e""#),
+        "invalid surrogate pair at byte 13"
+    );
+    // Alone.
+    assert_eq!(error(r#""\ud83e""#), "invalid unicode escape at byte 7");
+    assert_eq!(error(r#""\udd80""#), "invalid unicode escape at byte 7");
+}
+
+#[test]
+fn nesting_is_limited() {
+    let nested = |depth| format!("{}{}", "[".repeat(depth), "]".repeat(depth));
+    assert!(json::parse(&nested(MAX_DEPTH)).is_ok());
+    assert_eq!(
+        error(&nested(MAX_DEPTH + 1)),
+        format!("nested too deep at byte {}", MAX_DEPTH)
+    );
+
+    // Closed arrays and objects don't count.
+    let siblings = format!("[{}]", vec![nested(MAX_DEPTH - 1); 3].join(","));
+    assert!(json::parse(&siblings).is_ok());
+    assert!(json::parse(&"{\"a\":".repeat(MAX_DEPTH + 1)).is_err());
+    // Far deeper than the limit, refused before the stack runs out.
+    assert!(json::parse(&"[".repeat(1_000_000)).is_err());
+}
+
+#[test]
+fn broken_input_is_refused() {
+    assert_eq!(error(""), "unexpected end of input at byte 0");
+    assert_eq!(error("nul"), "invalid literal at byte 0");
+    assert_eq!(error("[1 2]"), "expected `,` or `]` at byte 4");
+    assert_eq!(error("{1: 2}"), "expected a string key at byte 1");
+    assert_eq!(error(r#"{"a" 2}"#), "expected `:` at byte 6");
+    assert_eq!(error(r#"{"a": 2"#), "expected `,` or `}` at byte 7");
+    assert_eq!(error(r#""open"#), "unterminated string at byte 5");
+    assert_eq!(error(r#""\x""#), "invalid escape at byte 3");
+    assert_eq!(error("1.2.3"), "invalid number at byte 5");
+    assert_eq!(error("{} {}"), "trailing characters at byte 3");
+}
@@ -0,0 +1,100 @@
+// # MultiMap and BiMap
+//
+// Grouping on collect, the per-key values and the entries of `MultiMap`; both lookups, the uniqueness of
+// each side and the entries of `BiMap`.
+
+use rust_trainning::collections::bimap::{self, BiMap};
+use rust_trainning::collections::multimap::{self, MultiMap};
+
+#[test]
+fn multimap_groups_values_by_key() {
+    let vikings = [
+        ("Norway", "Einar"),
+        ("Denmark", "Olaf"),
+        ("Norway", "Harald"),
+        ("Norway", "Astrid"),
+    ];
+    let mut by_country: MultiMap<&str, &str> = vikings.into_iter().collect();
+    assert_eq!((by_country.len(), by_country.keys_len()), (4, 2));
+    assert_eq!(
+        by_country.get_all("Norway").collect::<Vec<_>>(),
+        [&"Einar", &"Harald", &"Astrid"]
+    );
+    assert_eq!(by_country.get("Denmark"), Some(&"Olaf"));
+    assert_eq!(by_country.get_all("Iceland").count(), 0);
+    assert_eq!(by_country.iter().count(), 4);
+
+    by_country.retain(|_, name| name.starts_with(['A', 'E', 'I', 'O', 'U']));
+    assert_eq!((by_country.len(), by_country.keys_len()), (3, 2));
+    assert_eq!(&by_country["Norway"], ["Einar", "Astrid"]);
+    assert_eq!(by_country.remove("Norway"), Some(vec!["Einar", "Astrid"]));
+    assert_eq!(by_country, MultiMap::from([("Denmark", "Olaf")]));
+}
+
+#[test]
+fn multimap_entries() {
+    let mut scores = MultiMap::new();
+    scores.entry("Sunface").push(98);
+    assert_eq!(scores.entry("Sunface").or_insert(0), [98]);
+    assert_eq!(scores.entry("Sunface").push(95), [98, 95]);
+    scores.entry("Sunface").and_modify(|all| all.sort());
+    assert_eq!(&scores["Sunface"], [95, 98]);
+
+    match scores.entry("Daniel") {
+        multimap::Entry::Vacant(entry) => assert_eq!(entry.insert(69), [69]),
+        multimap::Entry::Occupied(_) => panic!("no Daniel yet"),
+    }
+    match scores.entry("Sunface") {
+        multimap::Entry::Occupied(entry) => assert_eq!(entry.remove(), [95, 98]),
+        multimap::Entry::Vacant(_) => panic!("Sunface has scores"),
+    }
+    assert_eq!((scores.len(), scores.keys_len()), (1, 1));
+}
+
+#[test]
+fn bimap_keeps_both_sides_unique() {
+    let mut ranks: BiMap<&str, u32> = [("Chinese Team", 1), ("France Team", 2)].into();
+    assert_eq!(ranks.get_by_left("France Team"), Some(&2));
+    assert_eq!(ranks.get_by_right(&1), Some(&"Chinese Team"));
+
+    assert_eq!(
+        ranks.try_insert("American Team", 2),
+        Err(("American Team", 2))
+    );
+    assert_eq!(ranks.len(), 2);
+
+    // Takes rank 2 from France, and the American Team had no rank to lose.
+    assert_eq!(ranks.insert("American Team", 2), [("France Team", 2)]);
+    // Moves the Chinese Team from rank 1 to rank 2, which the American Team loses.
+    let mut removed = ranks.insert("Chinese Team", 2);
+    removed.sort();
+    assert_eq!(removed, [("American Team", 2), ("Chinese Team", 1)]);
+    assert_eq!(ranks, BiMap::from([("Chinese Team", 2)]));
+    assert!(!ranks.contains_right(&1));
+    assert_eq!(ranks.remove_by_right(&2), Some(("Chinese Team", 2)));
+    assert!(ranks.is_empty() && ranks.right_values().next().is_none());
+}
+
+#[test]
+fn bimap_entries_from_both_sides() {
+    let mut ranks: BiMap<&str, u32> = BiMap::new();
+    assert_eq!(ranks.entry_by_left("Chinese Team").or_insert(1), Ok(&1));
+    assert_eq!(ranks.entry_by_left("Chinese Team").or_insert(5), Ok(&1));
+    assert_eq!(ranks.entry_by_left("France Team").or_insert(1), Err(1));
+    assert_eq!(
+        ranks.entry_by_right(2).or_insert("Chinese Team"),
+        Err("Chinese Team")
+    );
+    assert_eq!(
+        ranks.entry_by_right(2).or_insert("France Team"),
+        Ok(&"France Team")
+    );
+    assert_eq!(ranks.get_by_left("France Team"), Some(&2));
+
+    match ranks.entry_by_right(1) {
+        bimap::Entry::Occupied(entry) => assert_eq!(entry.remove(), (1, "Chinese Team")),
+        bimap::Entry::Vacant(_) => panic!("rank 1 is taken"),
+    }
+    assert_eq!(ranks.get_by_left("Chinese Team"), None);
+    assert_eq!(ranks.len(), 1);
+}
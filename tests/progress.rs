@@ -0,0 +1,129 @@
+// # Progress
+//
+// A learner's passes and hints survive a save and a reload, and the report counts them per topic and
+// points to the first unsolved exercise of the curriculum.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use rust_trainning::progress::{self, Learner, Progress};
+use rust_trainning::registry::Example;
+
+fn temp_file(name: &str) -> PathBuf {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_file(&path);
+    path
+}
+
+fn report(name: &str, progress: &Progress) -> String {
+    let mut out = Vec::new();
+    progress::write_report(&mut out, name, progress).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+// The exercises in the order they are suggested.
+fn exercises() -> Vec<&'static Example> {
+    progress::curriculum()
+        .into_iter()
+        .flat_map(|topic| topic.exercises())
+        .collect()
+}
+
+#[test]
+fn only_the_latest_result_counts() {
+    let exercise = exercises()[0];
+    let mut learner = Learner::default();
+
+    learner.record(exercise, true);
+    assert!(learner.has_passed(exercise));
+    learner.record(exercise, false);
+    assert!(!learner.has_passed(exercise));
+}
+
+#[test]
+fn passes_and_hints_survive_a_reload() {
+    let path = temp_file("progress-round-trip.json");
+    let [first, second] = [exercises()[0], exercises()[1]];
+
+    let mut progress = Progress::load(&path).unwrap();
+    assert!(progress.learner("alice").is_none());
+    let alice = progress.learner_mut("alice");
+    alice.record(first, true);
+    alice.reveal_hint(second);
+    alice.reveal_hint(second);
+    progress.learner_mut("bob").record(second, true);
+    progress.save().unwrap();
+
+    let reloaded = Progress::load(&path).unwrap();
+    let alice = reloaded.learner("alice").unwrap();
+    assert!(alice.has_passed(first));
+    assert!(!alice.has_passed(second));
+    assert_eq!(alice.hints_revealed(second), 2);
+    assert_eq!(alice.hints_used(), 2);
+    let bob = reloaded.learner("bob").unwrap();
+    assert!(bob.has_passed(second));
+    assert_eq!(bob.hints_used(), 0);
+}
+
+#[test]
+fn the_next_exercise_follows_the_curriculum() {
+    let exercises = exercises();
+    let mut learner = Learner::default();
+
+    // Passing exercises out of order doesn't change which one comes first.
+    for exercise in exercises.iter().skip(1).step_by(2) {
+        learner.record(exercise, true);
+    }
+    for exercise in exercises.iter().step_by(2) {
+        assert_eq!(
+            progress::next_unsolved(&learner).unwrap().path(),
+            exercise.path()
+        );
+        learner.record(exercise, true);
+    }
+    assert!(progress::next_unsolved(&learner).is_none());
+}
+
+#[test]
+fn the_report_counts_passes_per_topic() {
+    let path = temp_file("progress-report.json");
+    let topics = progress::curriculum();
+    let first = topics[0];
+    let exercises = exercises();
+
+    let mut progress = Progress::load(&path).unwrap();
+    for exercise in first.exercises() {
+        progress.learner_mut("alice").record(exercise, true);
+    }
+    let report = report("alice", &progress);
+
+    let width = topics.iter().map(|t| t.title.len()).max().unwrap();
+    for topic in &topics {
+        let total = topic.exercises().count();
+        let done = if topic.name == first.name { total } else { 0 };
+        let line = format!(
+            "{:width$}  {:>2}/{:<2} [{}{}]\n",
+            topic.title,
+            done,
+            total,
+            "#".repeat(done),
+            ".".repeat(total - done),
+            width = width
+        );
+        assert!(
+            report.contains(&line),
+            "no `{}` in:\n{}",
+            line.trim_end(),
+            report
+        );
+    }
+
+    let done = first.exercises().count();
+    assert!(report.contains(&format!(
+        "Overall: {}/{} exercises passed\n",
+        done,
+        exercises.len()
+    )));
+    let next = exercises[done];
+    assert!(report.ends_with(&format!("Next: {} ({})\n", next.path(), next.title)));
+}
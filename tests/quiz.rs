@@ -0,0 +1,129 @@
+// # Quiz
+//
+// The expected answers come from running the snippets, so these tests only make sure every snippet runs,
+// that it's still the lesson's code, and that typed answers are compared the way the quiz promises.
+
+use std::io::Cursor;
+
+use rust_trainning::quiz::{self, answers_match};
+use rust_trainning::{lesson, question, registry};
+
+fn without_whitespace(s: &str) -> String {
+    s.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[test]
+fn every_question_has_an_answer() {
+    let questions = quiz::questions(None).unwrap();
+    assert!(!questions.is_empty());
+
+    for question in questions {
+        let expected = question
+            .expected()
+            .unwrap_or_else(|e| panic!("{}: `{}` panicked: {}", question.topic, question.expr, e));
+        assert!(
+            !expected.is_empty(),
+            "{}: {}",
+            question.topic,
+            question.expr
+        );
+    }
+}
+
+// `stringify!` respaces the snippet, so the lesson is searched without whitespace.
+#[test]
+fn every_snippet_is_still_in_its_lesson() {
+    let mut missing = Vec::new();
+    for topic in registry::topics() {
+        let lesson = without_whitespace(lesson::body(topic.source));
+        for question in topic.quiz {
+            missing.extend(
+                question
+                    .code()
+                    .into_iter()
+                    .filter(|line| !lesson.contains(&without_whitespace(line)))
+                    .map(|line| format!("{}: `{}`", topic.name, line)),
+            );
+        }
+    }
+
+    assert!(
+        missing.is_empty(),
+        "not in the lesson anymore:\n{}",
+        missing.join("\n")
+    );
+}
+
+#[test]
+fn answers_come_from_running_the_snippet() {
+    let string = quiz::questions(Some("string")).unwrap();
+    assert_eq!(string[0].expr, "&s[7..10]");
+    assert_eq!(string[0].expected().unwrap(), "\"ぁ\"");
+
+    let coercion = quiz::questions(Some("type_coersion")).unwrap();
+    assert_eq!(coercion[0].expected().unwrap(), "232");
+}
+
+#[test]
+fn typed_answers_ignore_whitespace_and_quotes() {
+    assert!(answers_match("\"ぁ\"", "ぁ"));
+    assert!(answers_match("\"ぁ\"", " \"ぁ\" \n"));
+    assert!(answers_match("[2, 3]", "[2,3]"));
+    assert!(answers_match("Some(98)", "Some( 98 )"));
+    assert!(answers_match("\"hello world\"", "hello world"));
+
+    assert!(!answers_match("\"hello world\"", "\"helloworld\""));
+    assert!(!answers_match("232", "744"));
+}
+
+#[test]
+fn a_session_is_scored() {
+    let questions = quiz::questions(Some("d_slice")).unwrap();
+    let answers: String = questions
+        .iter()
+        .enumerate()
+        .map(|(i, q)| {
+            if i == 0 {
+                "wrong\n".to_string()
+            } else {
+                q.expected().unwrap() + "\n"
+            }
+        })
+        .collect();
+
+    let mut output = Vec::new();
+    let score = quiz::ask(&questions, &mut Cursor::new(answers), &mut output).unwrap();
+
+    assert_eq!(score.asked, questions.len());
+    assert_eq!(score.correct, questions.len() - 1);
+    assert!(String::from_utf8(output)
+        .unwrap()
+        .contains(&format!("Score: {}/{}", score.correct, score.asked)));
+}
+
+#[test]
+fn a_snippet_that_panics_is_skipped() {
+    let panics = question!("d_slice", { let v: Vec<i32> = Vec::new(); } => v[0]);
+    assert!(panics
+        .expected()
+        .unwrap_err()
+        .contains("index out of bounds"));
+
+    let questions = quiz::questions(Some("d_slice")).unwrap();
+    let questions = [&panics, questions[0]];
+    let answer = questions[1].expected().unwrap() + "\n";
+
+    let mut output = Vec::new();
+    let score = quiz::ask(&questions, &mut Cursor::new(answer), &mut output).unwrap();
+
+    assert_eq!(
+        score,
+        quiz::Score {
+            asked: 1,
+            correct: 1
+        }
+    );
+    assert!(String::from_utf8(output).unwrap().starts_with(
+        "Question 1/2 (d_slice) is skipped, its snippet panicked: index out of bounds"
+    ));
+}
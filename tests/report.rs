@@ -0,0 +1,72 @@
+// # Reports
+//
+// The JSON Lines and JUnit reports are read by other tools, so their shape is checked on a topic with
+// passing and failing exercises (`string`) and one that panics (`error_handling`).
+
+use std::process::Command;
+
+use rust_trainning::json::{self, Value};
+
+fn report(args: &[&str]) -> String {
+    let output = Command::new(env!("CARGO_BIN_EXE_rust-trainning"))
+        .arg("report")
+        .args(args)
+        .env("RUST_TRAINNING_LEARNER", "alice")
+        .output()
+        .expect("failed to run the binary");
+
+    assert!(output.status.success(), "{:?}", output);
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn json_lines_have_one_result_per_example() {
+    let lines: Vec<Value> = report(&["string"])
+        .lines()
+        .map(|line| json::parse(line).unwrap())
+        .collect();
+    let field = |line: &Value, key: &str| line.get(key).and_then(Value::as_str).map(str::to_string);
+    let example = |id: &str| {
+        lines
+            .iter()
+            .find(|line| field(line, "example").as_deref() == Some(id))
+            .unwrap_or_else(|| panic!("no result for `{}`", id))
+    };
+
+    assert_eq!(
+        lines.len(),
+        rust_trainning::registry::select("string").unwrap().len()
+    );
+    for line in &lines {
+        assert_eq!(field(line, "learner").as_deref(), Some("alice"));
+        assert_eq!(field(line, "topic").as_deref(), Some("string"));
+        assert!(line.get("duration_ms").and_then(Value::as_f64).is_some());
+    }
+
+    let failed = example("exercise_1");
+    assert_eq!(field(failed, "status").as_deref(), Some("fail"));
+    assert!(field(failed, "message")
+        .unwrap()
+        .contains("src/topics/string.rs"));
+
+    let passed = example("exercise_3");
+    assert_eq!(field(passed, "status").as_deref(), Some("pass"));
+    assert_eq!(
+        field(passed, "stdout").as_deref(),
+        Some("Slice2: ぁ\nSuccess!\n")
+    );
+    assert_eq!(passed.get("message"), Some(&Value::Null));
+}
+
+#[test]
+fn junit_reports_panics_as_errors() {
+    let xml = report(&["--format", "junit", "error_handling"]);
+
+    assert!(xml.starts_with("<?xml"));
+    assert!(
+        xml.contains("<testsuite name=\"error_handling\" tests=\"2\" failures=\"0\" errors=\"1\"")
+    );
+    assert!(xml.contains("<property name=\"learner\" value=\"alice\"/>"));
+    assert!(xml.contains("<error message=\"explicit panic\" type=\"panic\">"));
+    assert!(xml.contains("<system-out>15\nSuccess!\n</system-out>"));
+}
@@ -0,0 +1,101 @@
+// # Rope
+//
+// A `String` is the reference: every edit is made to both, and the rope must read the same afterwards,
+// chunk by chunk, char by char and line by line. Texts mix one, two, three and four byte chars with
+// newlines and grow past one chunk, so that edits land next to multi-byte chars, on chunk boundaries and
+// across chunks.
+
+use std::borrow::Cow;
+
+use rust_trainning::text::{self, Rope};
+
+mod common;
+
+use common::Random;
+
+const PIECES: &[&str] = &["a", "é", "ぁ", "🚀", "\n", "hello, ", "line\n", "🌘🌘\n"];
+
+fn check(rope: &Rope, expected: &str) {
+    assert_eq!(rope.to_string(), expected);
+    assert_eq!(rope.len_bytes(), expected.len());
+    assert_eq!(rope.len_chars(), expected.chars().count());
+    assert_eq!(rope.len_lines(), expected.split('\n').count());
+    assert_eq!(rope.chunks().collect::<String>(), expected);
+}
+
+#[test]
+fn edits_match_a_string() {
+    let mut random = Random::new(7);
+    let mut rope = Rope::new();
+    let mut expected = String::new();
+
+    for step in 0..3000 {
+        let chars = expected.chars().count();
+        if chars > 0 && random.below(3) == 0 {
+            let start = random.below(chars);
+            let end = (start + random.below(if step % 100 == 0 { 3000 } else { 8 })).min(chars);
+            rope.remove(start..end);
+            let bytes = text::char_to_byte(&expected, start).unwrap()
+                ..text::char_to_byte(&expected, end).unwrap();
+            expected.replace_range(bytes, "");
+        } else {
+            let at = random.below(chars + 1);
+            let piece = if step % 250 == 0 {
+                PIECES[random.below(PIECES.len())].repeat(300)
+            } else {
+                PIECES[random.below(PIECES.len())].to_string()
+            };
+            rope.insert(at, &piece);
+            expected.insert_str(text::char_to_byte(&expected, at).unwrap(), &piece);
+        }
+
+        if step % 100 == 0 {
+            check(&rope, &expected);
+        }
+    }
+    check(&rope, &expected);
+    assert!(rope.chunks().count() > 1);
+}
+
+#[test]
+fn positions_lines_and_slices() {
+    let mut expected = String::new();
+    for i in 0..400 {
+        expected.push_str(&format!("{} ぁ🚀 {}\n", i, "é".repeat(i % 7)));
+    }
+    let rope = Rope::from(expected.as_str());
+    check(&rope, &expected);
+
+    for (at, (byte, _)) in expected.char_indices().enumerate().step_by(13) {
+        assert_eq!(rope.char_to_byte(at), byte);
+        assert_eq!(
+            rope.char_to_line(at),
+            expected[..byte].matches('\n').count()
+        );
+    }
+    assert_eq!(rope.char_to_byte(rope.len_chars()), expected.len());
+
+    let lines: Vec<&str> = expected.split_inclusive('\n').collect();
+    assert_eq!(rope.len_lines(), lines.len() + 1);
+    for (number, line) in lines.iter().enumerate() {
+        assert_eq!(rope.line(number), *line);
+    }
+    assert_eq!(rope.line(lines.len()), "");
+    assert_eq!(rope.line_to_char(1), lines[0].chars().count());
+
+    let chars: Vec<char> = expected.chars().collect();
+    for (start, end) in [(0, 0), (5, 9), (100, 3000), (0, chars.len())] {
+        let slice: String = chars[start..end].iter().collect();
+        assert_eq!(rope.slice(start..end), slice);
+    }
+
+    // Within a chunk the slice is borrowed, across chunks it is copied.
+    assert!(matches!(rope.slice(2..5), Cow::Borrowed("ぁ🚀 ")));
+    assert!(matches!(rope.slice(0..rope.len_chars()), Cow::Owned(_)));
+}
+
+#[test]
+#[should_panic(expected = "char index 4 out of a rope of 3 chars")]
+fn a_position_past_the_end_panics() {
+    Rope::from("abc").insert(4, "d");
+}
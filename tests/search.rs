@@ -0,0 +1,53 @@
+// # Search
+//
+// The ranking is what makes the search useful: a type or function named after the term comes first, then
+// names that contain it, then headings, comments and code. The terms below are names the lessons are
+// unlikely to lose.
+
+use rust_trainning::search::{self, Hit};
+
+fn paths(hits: &[Hit]) -> Vec<String> {
+    hits.iter().map(Hit::path).collect()
+}
+
+#[test]
+fn an_exact_name_ranks_first() {
+    let hits = search::search("Viking");
+
+    assert_eq!(hits[0].path(), "hashmap::Viking");
+    assert_eq!(hits[0].location(), "src/topics/hashmap.rs:106");
+    assert_eq!(hits[0].snippet, "struct Viking {");
+    assert!(hits[0].score >= 100);
+    // The `impl Viking` block is a group of its own, under the same name.
+    assert!(paths(&hits[1..]).contains(&"hashmap::Viking".to_string()));
+}
+
+#[test]
+fn names_rank_above_comments_and_code() {
+    let hits = search::search("fibonacci");
+
+    assert_eq!(
+        paths(&hits[..2]),
+        ["iterators::_Fibonacci", "iterators::_fibonnaci"]
+    );
+    for pair in hits.windows(2) {
+        assert!(pair[0].score >= pair[1].score, "not sorted by score");
+    }
+
+    // Only a comment mentions it.
+    let hits = search::search("box::leak");
+    assert_eq!(hits[0].path(), "lifetimes");
+    assert!(hits[0].snippet.contains("`Box::leak`"));
+    assert!(hits[0].score >= 30 && hits[0].score < 50);
+}
+
+#[test]
+fn search_ignores_case_and_surrounding_spaces() {
+    let lower = search::search("viking");
+    let upper = search::search("  VIKING ");
+
+    assert!(!lower.is_empty());
+    assert_eq!(paths(&lower), paths(&upper));
+    assert!(search::search("   ").is_empty());
+    assert!(search::search("no lesson talks about this").is_empty());
+}
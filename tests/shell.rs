@@ -0,0 +1,89 @@
+// # Shell
+//
+// The shell is driven line by line here, the way the line editor feeds it.
+
+use rust_trainning::shell::editor::Editor;
+use rust_trainning::shell::Shell;
+
+fn execute(shell: &mut Shell, line: &str) -> String {
+    let mut out = Vec::new();
+    shell.execute(line, &mut out).unwrap();
+    String::from_utf8(out).unwrap()
+}
+
+#[test]
+fn show_prints_the_comment_block_and_the_source() {
+    let mut shell = Shell::new();
+    execute(&mut shell, "cd closures");
+
+    let shown = execute(&mut shell, "show _example_closures_06");
+    assert!(shown.starts_with("--- src/topics/closures.rs:"));
+    assert!(shown.contains("pub fn _example_closures_06() {"));
+    assert!(shown.contains("--- `run` to see it:"));
+    assert_eq!(shell.prompt(), "closures::_example_closures_06> ");
+}
+
+#[test]
+fn next_and_prev_walk_through_the_functions() {
+    let mut shell = Shell::new();
+    execute(&mut shell, "cd closures");
+
+    assert!(execute(&mut shell, "next").contains("pub fn _example_closures_01()"));
+    assert!(execute(&mut shell, "next").contains("pub fn _example_closures_02()"));
+    assert!(execute(&mut shell, "prev").contains("pub fn _example_closures_01()"));
+    assert!(execute(&mut shell, "prev").contains("already at the first function"));
+}
+
+#[test]
+fn functions_of_another_topic_can_be_shown_directly() {
+    let mut shell = Shell::new();
+
+    let shown = execute(&mut shell, "show iterators::_example_iterators_06");
+    assert!(shown.contains("pub fn _example_iterators_06()"));
+    assert_eq!(shell.topic().map(|t| t.name), Some("iterators"));
+}
+
+#[test]
+fn mistakes_are_explained() {
+    let mut shell = Shell::new();
+
+    assert!(execute(&mut shell, "cd nope").contains("unknown topic `nope`"));
+    assert!(execute(&mut shell, "show x").contains("`cd` into a topic first"));
+    assert!(execute(&mut shell, "frobnicate").contains("unknown command `frobnicate`"));
+}
+
+#[test]
+fn completion_knows_commands_topics_and_functions() {
+    let mut shell = Shell::new();
+    assert_eq!(shell.complete("sh"), ["show"]);
+    assert_eq!(shell.complete("cd iter"), ["iterators"]);
+
+    execute(&mut shell, "cd closures");
+    assert!(shell
+        .complete("show _example_closures_0")
+        .contains(&"_example_closures_06".to_string()));
+}
+
+// Types `keys` into the line editor, as a terminal in raw mode would send them, until the input ends.
+fn type_keys(keys: &[u8]) -> Option<String> {
+    let mut out = Vec::new();
+    Editor::new()
+        .edit("> ", |_| Vec::new(), &mut &keys[..], &mut out)
+        .unwrap()
+}
+
+#[test]
+fn the_editor_ends_on_enter_and_on_ctrl_d() {
+    assert_eq!(type_keys(b"cd closures\r"), Some("cd closures".to_string()));
+    assert_eq!(type_keys(b"\x04"), None);
+    // Ctrl-D on a line deletes the character under the cursor: `ab`, left, Ctrl-D.
+    assert_eq!(type_keys(b"ab\x1b[D\x04\r"), Some("a".to_string()));
+}
+
+#[test]
+fn the_editor_returns_what_was_typed_when_the_input_ends() {
+    assert_eq!(type_keys(b""), None);
+    assert_eq!(type_keys(b"next"), Some("next".to_string()));
+    // Ctrl-D at the end of the line has nothing to delete, the end of input still ends it.
+    assert_eq!(type_keys(b"next\x04"), Some("next".to_string()));
+}
@@ -0,0 +1,7 @@
+// Still wrong, and the assertion says why in its own words.
+fn exercise_1() {
+    let mut s = String::from("hello, ");
+    s.push_str("world");
+
+    assert!(s.ends_with('!'), "{:?} has no exclamation mark", s);
+}
@@ -0,0 +1,3 @@
+fn exercise_1() {
+    loop {}
+}
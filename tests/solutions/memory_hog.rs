@@ -0,0 +1,5 @@
+// A gigabyte, more than the limit the test sets.
+fn exercise_1() {
+    let buffer = vec![1u8; 1 << 30];
+    println!("{}", buffer.len());
+}
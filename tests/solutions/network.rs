@@ -0,0 +1,7 @@
+// Even the loopback interface is down in the sandbox: nothing can be reached, not even this machine.
+fn exercise_1() {
+    let error = std::net::TcpStream::connect("127.0.0.1:1").unwrap_err();
+    assert_eq!(error.kind(), std::io::ErrorKind::NetworkUnreachable);
+
+    println!("Success!");
+}
@@ -0,0 +1,11 @@
+// The expected value had a typo.
+fn exercise_1() {
+    let mut s = String::from("hello, ");
+    s.push_str("world");
+    s.push('!');
+
+    assert_eq!(s, "hello, world!");
+    move_ownership(s);
+
+    println!("Success!");
+}
@@ -0,0 +1,7 @@
+// Wrong in another way: no assertion fails, an `unwrap` does.
+fn exercise_1() {
+    let s = String::from("hello, world");
+    let exclamation = s.find('!').unwrap();
+
+    println!("{}", exclamation);
+}
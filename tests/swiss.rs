@@ -0,0 +1,132 @@
+// # SwissTable
+//
+// The map is checked against `std::collections::HashMap` receiving the same operations. The keys come from
+// a small range, so that inserts hit existing keys and removes leave tombstones that later inserts reuse.
+// A hasher that sends every key to the same few groups makes the probing go far.
+
+use std::collections::HashMap as StdMap;
+use std::hash::{BuildHasher, BuildHasherDefault, Hasher};
+
+use rust_trainning::collections::swiss::{Entry, HashMap};
+
+mod common;
+
+use common::Random;
+
+// Only 16 different hashes, all with the same `h2`.
+#[derive(Default)]
+struct Colliding(u64);
+
+impl Hasher for Colliding {
+    fn finish(&self) -> u64 {
+        self.0 % 16
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 = self.0.wrapping_mul(31).wrapping_add(byte as u64);
+        }
+    }
+}
+
+fn differential<S: BuildHasher>(mut map: HashMap<u64, u64, S>, keys: usize, steps: usize) {
+    let mut expected = StdMap::new();
+    let mut random = Random::new(99);
+
+    for step in 0..steps {
+        let key = random.below(keys) as u64;
+        match random.below(6) {
+            0 | 1 => assert_eq!(
+                map.insert(key, step as u64),
+                expected.insert(key, step as u64)
+            ),
+            2 => assert_eq!(map.remove(&key), expected.remove(&key)),
+            3 => assert_eq!(map.get(&key), expected.get(&key)),
+            4 => {
+                *map.entry(key).or_insert(0) += 1;
+                *expected.entry(key).or_insert(0) += 1;
+            }
+            _ => {
+                map.entry(key).or_insert_with(|| step as u64);
+                expected.entry(key).or_insert_with(|| step as u64);
+            }
+        }
+        assert_eq!(map.len(), expected.len());
+    }
+
+    let mut entries: Vec<(u64, u64)> = map.iter().map(|(&k, &v)| (k, v)).collect();
+    let mut expected: Vec<(u64, u64)> = expected.into_iter().collect();
+    entries.sort();
+    expected.sort();
+    assert_eq!(entries, expected);
+}
+
+#[test]
+fn operations_match_std() {
+    differential(HashMap::new(), 500, 20_000);
+    differential(HashMap::with_capacity(1000), 50, 20_000);
+    differential(
+        HashMap::with_hasher(BuildHasherDefault::<Colliding>::default()),
+        200,
+        5_000,
+    );
+}
+
+#[test]
+fn growth_and_tombstones() {
+    let mut map = HashMap::new();
+    assert_eq!((map.buckets(), map.capacity()), (8, 7));
+    for i in 0..7 {
+        map.insert(i, i);
+    }
+    assert_eq!(map.buckets(), 8);
+    map.insert(7, 7);
+    assert_eq!(map.buckets(), 16);
+
+    // With every key in the same groups, the groups fill up and removing leaves tombstones.
+    let mut map = HashMap::with_hasher(BuildHasherDefault::<Colliding>::default());
+    for i in 0..100 {
+        map.insert(i, i);
+    }
+    for i in 0..90 {
+        map.remove(&i);
+    }
+    assert!(map.tombstones() > 0);
+    let buckets = map.buckets();
+    // Inserting and removing over and over reuses tombstones, or rebuilds the table at the same size.
+    for i in 1000..5000 {
+        map.insert(i, i);
+        map.remove(&i);
+    }
+    assert_eq!(map.buckets(), buckets);
+    assert_eq!(map.len(), 10);
+    assert_eq!(map.get(&95), Some(&95));
+}
+
+#[test]
+fn entry_api_as_in_the_lesson() {
+    let mut player_stats = HashMap::new();
+    player_stats.entry("health").or_insert(100);
+    assert_eq!(player_stats["health"], 100);
+
+    player_stats.entry("health").or_insert_with(|| 42);
+    let health = player_stats.entry("health").or_insert(50);
+    *health -= 50;
+    assert_eq!(player_stats["health"], 50);
+
+    match player_stats.entry("mana") {
+        Entry::Vacant(entry) => assert_eq!(*entry.insert(7), 7),
+        Entry::Occupied(_) => panic!("no mana yet"),
+    }
+    match player_stats.entry("mana") {
+        Entry::Occupied(entry) => assert_eq!(entry.remove(), 7),
+        Entry::Vacant(_) => panic!("mana was inserted"),
+    }
+
+    let mut words: HashMap<String, usize> = HashMap::default();
+    for word in "the cat and the hat and the bat".split(' ') {
+        *words.entry(word.to_string()).or_default() += 1;
+    }
+    assert_eq!(words.get("the"), Some(&3));
+    assert_eq!(words.len(), 5);
+}
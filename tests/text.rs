@@ -0,0 +1,89 @@
+// # Text
+//
+// The strings of `string::example_4` and `string::exercise_3`, plus the clusters that `.chars()` splits.
+
+use rust_trainning::text::{self, grapheme, width};
+
+const ROCKET: &str = "The 🚀 goes to the 🌘";
+
+#[test]
+fn chars_and_bytes() {
+    assert_eq!(text::char_slice(ROCKET, 4, 5), Some("🚀"));
+    assert_eq!(text::char_slice(ROCKET, 18, 19), Some("🌘"));
+    assert_eq!(text::char_slice(ROCKET, 19, 19), Some(""));
+    assert_eq!(text::char_slice(ROCKET, 18, 20), None);
+    assert_eq!(text::char_slice(ROCKET, 5, 4), None);
+    assert_eq!(text::char_slice("hello, ぁまピ", 7, 8), Some("ぁ"));
+
+    assert_eq!(text::char_to_byte(ROCKET, 5), Some(8));
+    assert_eq!(text::char_to_byte(ROCKET, 19), Some(ROCKET.len()));
+    assert_eq!(text::char_to_byte(ROCKET, 20), None);
+    assert_eq!(text::byte_to_char(ROCKET, 8), Some(5));
+    assert_eq!(text::byte_to_char(ROCKET, 6), None);
+    assert_eq!(text::byte_to_char(ROCKET, 100), None);
+
+    assert_eq!(text::substring(ROCKET, 4, 8), Some("🚀"));
+    assert_eq!(text::substring(ROCKET, 4, 6), None);
+    assert_eq!(text::substring(ROCKET, 0, 100), None);
+}
+
+#[test]
+fn graphemes_keep_what_a_reader_sees_together() {
+    let cases = [
+        ("e\u{301}te\u{301}", vec!["e\u{301}", "t", "e\u{301}"]),
+        ("👍🏽!", vec!["👍🏽", "!"]),
+        ("👩\u{200D}🚀 ok", vec!["👩\u{200D}🚀", " ", "o", "k"]),
+        ("🇫🇷🇯🇵🇩", vec!["🇫🇷", "🇯🇵", "🇩"]),
+        ("a\r\n\nb", vec!["a", "\r\n", "\n", "b"]),
+        (
+            "\u{1100}\u{1161}\u{11A8}가",
+            vec!["\u{1100}\u{1161}\u{11A8}", "가"],
+        ),
+        ("", vec![]),
+    ];
+    for (s, clusters) in cases {
+        assert_eq!(
+            grapheme::graphemes(s).collect::<Vec<_>>(),
+            clusters,
+            "{:?}",
+            s
+        );
+    }
+
+    let s = "🇫🇷 cafe\u{301}";
+    assert_eq!(grapheme::grapheme_count(s), 6);
+    assert_eq!(text::grapheme_slice(s, 0, 1), Some("🇫🇷"));
+    assert_eq!(text::grapheme_slice(s, 5, 6), Some("e\u{301}"));
+    assert_eq!(text::grapheme_slice(s, 6, 6), Some(""));
+    assert_eq!(text::grapheme_slice(s, 5, 7), None);
+}
+
+#[test]
+fn display_width() {
+    assert_eq!(width::char_width('a'), 1);
+    assert_eq!(width::char_width('ぁ'), 2);
+    assert_eq!(width::char_width('\u{301}'), 0);
+    assert_eq!(text::width(ROCKET), 21);
+    assert_eq!(text::width("hello, ぁまピ"), 13);
+    assert_eq!(text::width("cafe\u{301}"), 4);
+    assert_eq!(text::width("👩\u{200D}🚀"), 2);
+    assert_eq!(text::width("❤\u{FE0F}"), 2);
+    assert_eq!(text::width("❤"), 1);
+}
+
+#[test]
+fn only_nonspacing_marks_and_format_chars_are_zero_width() {
+    // Devanagari spacing marks (Mc) take a column: the visarga and the vowel signs aa, i, ii and au.
+    for mark in ['\u{903}', '\u{93E}', '\u{93F}', '\u{940}', '\u{94C}'] {
+        assert_eq!(width::char_width(mark), 1, "U+{:04X}", mark as u32);
+    }
+    // Between the Hebrew points, paseq, sof pasuq and nun hafukha are punctuation.
+    for c in ['\u{5C0}', '\u{5C3}', '\u{5C6}'] {
+        assert_eq!(width::char_width(c), 1, "U+{:04X}", c as u32);
+    }
+    assert_eq!(width::char_width('\u{5C1}'), 0);
+    assert_eq!(width::char_width('\u{94D}'), 0);
+    assert_eq!(width::char_width('\u{200B}'), 0);
+    assert_eq!(width::char_width('\u{20DD}'), 0);
+    assert_eq!(width::char_width('\u{FF21}'), 2);
+}
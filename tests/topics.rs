@@ -0,0 +1,34 @@
+// # Topics
+//
+// The lessons' own types and functions, used from outside of the crate the way the exercise repos that
+// depend on it use them.
+
+use rust_trainning::topics::closures::_factory;
+use rust_trainning::topics::generics::{Array, Point2};
+use rust_trainning::topics::j_methods_vs_associated_fn::{TrafficLight, TrafficLightColorEnum};
+
+#[test]
+fn generic_types_can_be_built_outside_of_the_lesson() {
+    let mixed = Point2 { x: 5, y: 10.0 }.mixup(Point2 { x: "Point", y: '#' });
+    assert_eq!((mixed.x, mixed.y), (5, '#'));
+
+    let array = Array { data: [1, 2, 3] };
+    assert_eq!(array.data.len(), 3);
+}
+
+#[test]
+fn methods_are_callable_outside_of_the_lesson() {
+    let mut light = TrafficLight {
+        color: "red".to_string(),
+    };
+    light.change_state();
+    assert_eq!(light.color, "green");
+
+    assert_eq!(TrafficLightColorEnum::Yellow.color(), "yellow");
+}
+
+#[test]
+fn returned_closures_are_callable_outside_of_the_lesson() {
+    assert_eq!(_factory(2)(1), 6);
+    assert_eq!(_factory(0)(1), 6);
+}
@@ -0,0 +1,111 @@
+// # TracingVec
+//
+// The history follows the capacity of the `Vec` inside, whatever the operation that changed it, and
+// records nothing when the capacity was reserved up front.
+
+use rust_trainning::collections::tracing_vec::{Growth, TracingVec};
+
+fn capacities(v: &TracingVec<u64>) -> Vec<(&'static str, usize, usize)> {
+    v.history()
+        .iter()
+        .map(|growth| (growth.operation, growth.old_capacity, growth.new_capacity))
+        .collect()
+}
+
+// Does to a plain `Vec` what the `TracingVec` under test does, and writes down what it should record: by
+// how much a `Vec` grows is up to std.
+fn mirror(
+    plain: &mut Vec<u64>,
+    expected: &mut Vec<(&'static str, usize, usize)>,
+    operation: &'static str,
+    f: impl FnOnce(&mut Vec<u64>),
+) {
+    let before = plain.capacity();
+    f(plain);
+    if plain.capacity() != before {
+        expected.push((operation, before, plain.capacity()));
+    }
+}
+
+#[test]
+fn every_change_of_capacity_is_recorded() {
+    let mut v = TracingVec::new();
+    let mut expected = Vec::new();
+    let mut plain = Vec::new();
+    for i in 0..100 {
+        mirror(&mut plain, &mut expected, "push", |plain| plain.push(i));
+        v.push(i);
+    }
+    assert_eq!(capacities(&v), expected);
+    assert_eq!(v, plain);
+    assert!(v.moves() < v.history().len());
+    let (_, _, first) = expected[0];
+    assert_eq!(
+        v.history()[0],
+        Growth {
+            operation: "push",
+            len: 0,
+            old_capacity: 0,
+            new_capacity: first,
+            moved: false,
+        }
+    );
+    // The second growth is the push that found the first buffer full.
+    let (_, old, new) = expected[1];
+    assert_eq!(
+        v.history()[1].to_string(),
+        format!("push at len {}: capacity {} -> {}", first, old, new)
+    );
+
+    v.drain(10..);
+    plain.drain(10..);
+    v.shrink_to_fit();
+    mirror(
+        &mut plain,
+        &mut expected,
+        "shrink_to_fit",
+        Vec::shrink_to_fit,
+    );
+    assert_eq!(v.history().last().unwrap().operation, "shrink_to_fit");
+    v.insert(0, 7);
+    mirror(&mut plain, &mut expected, "insert", |plain| {
+        plain.insert(0, 7)
+    });
+    assert_eq!(capacities(&v), expected);
+    assert_eq!(v, plain);
+}
+
+#[test]
+fn reserving_up_front_leaves_nothing_to_trace() {
+    let mut v = TracingVec::with_capacity(64);
+    v.extend(&[1u64, 2, 3]);
+    v.extend(4..60);
+    v.insert(0, 0);
+    assert_eq!(v.remove(0), 0);
+    assert_eq!(v.pop(), Some(59));
+    assert_eq!(v.drain(..3).collect::<Vec<_>>(), [1, 2, 3]);
+    assert!(v.history().is_empty());
+    assert_eq!((v.len(), v.capacity()), (55, 64));
+
+    // Nothing was reserved here: `extend` reserves what the iterator announces (nothing, for a `filter`),
+    // then grows as it pushes.
+    let v: TracingVec<u64> = (0..10).filter(|i| i % 2 == 0).collect();
+    assert_eq!(v, [0, 2, 4, 6, 8]);
+    let (mut plain, mut expected) = (Vec::new(), Vec::new());
+    for i in [0, 2, 4, 6, 8] {
+        mirror(&mut plain, &mut expected, "extend", |plain| plain.push(i));
+    }
+    assert_eq!(capacities(&v), expected);
+}
+
+#[test]
+fn the_default_is_empty_for_any_element_type() {
+    // No `Default` here, as for most element types.
+    #[derive(Debug, PartialEq)]
+    struct Viking(&'static str);
+
+    let mut v: TracingVec<Viking> = TracingVec::default();
+    assert!(v.is_empty() && v.history().is_empty());
+    v.push(Viking("Einar"));
+    assert_eq!(v, [Viking("Einar")]);
+}
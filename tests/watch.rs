@@ -0,0 +1,55 @@
+// # Watch mode
+//
+// `Watcher::changed` on a directory of its own: new and modified `.rs` files are reported once, other
+// files never, and files that come and go (atomic saves, `git checkout`) don't stop it.
+
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use rust_trainning::watch::Watcher;
+
+fn touch(path: &Path, seconds_ago: u64) {
+    fs::write(path, "fn main() {}\n").unwrap();
+    let time = SystemTime::now() - Duration::from_secs(seconds_ago);
+    File::options()
+        .write(true)
+        .open(path)
+        .unwrap()
+        .set_modified(time)
+        .unwrap();
+}
+
+#[test]
+fn reports_each_change_once() {
+    let dir = Path::new(env!("CARGO_TARGET_TMPDIR")).join("watch");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    let closures = dir.join("closures.rs");
+    let names = |paths: Vec<PathBuf>| -> Vec<String> {
+        paths
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect()
+    };
+
+    touch(&closures, 60);
+    let mut watcher = Watcher::new(&dir).unwrap();
+    assert!(watcher.changed().unwrap().is_empty());
+
+    touch(&closures, 30);
+    touch(&dir.join("string.rs"), 30);
+    fs::write(dir.join("notes.txt"), "not a lesson").unwrap();
+    assert_eq!(
+        names(watcher.changed().unwrap()),
+        ["closures.rs", "string.rs"]
+    );
+    assert!(watcher.changed().unwrap().is_empty());
+
+    // Saved the way editors do: a temporary file renamed over the lesson.
+    fs::remove_file(&closures).unwrap();
+    assert!(watcher.changed().unwrap().is_empty());
+    touch(&dir.join(".closures.rs.swp"), 10);
+    fs::rename(dir.join(".closures.rs.swp"), &closures).unwrap();
+    assert_eq!(names(watcher.changed().unwrap()), ["closures.rs"]);
+}